@@ -0,0 +1,145 @@
+use crate::createx::create2_address;
+use alloy_primitives::{Address, B256, keccak256};
+use thiserror::Error;
+
+/// The largest immutable args payload OpenZeppelin's `Clones` accepts.
+pub const MAX_IMMUTABLE_ARGS_LENGTH: usize = 24531;
+
+const CLONE_CREATION_HEAD: &[u8] = &[0x3d, 0x60, 0x2d, 0x80, 0x60, 0x0a, 0x3d, 0x39, 0x81, 0xf3];
+const CLONE_WITH_ARGS_CREATION_HEAD: &[u8] = &[0x3d, 0x81, 0x60, 0x0a, 0x3d, 0x39, 0xf3];
+const CLONE_RUNTIME_HEAD: &[u8] = &[0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d, 0x3d, 0x36, 0x3d, 0x73];
+const CLONE_RUNTIME_TAIL: &[u8] = &[
+    0x5a, 0xf4, 0x3d, 0x82, 0x80, 0x3e, 0x90, 0x3d, 0x91, 0x60, 0x2b, 0x57, 0xfd, 0x5b, 0xf3,
+];
+
+/// Errors raised when building clone creation code.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CloneError {
+    #[error("immutable args are {0} bytes, exceeding the {MAX_IMMUTABLE_ARGS_LENGTH} byte limit")]
+    ArgumentsTooLong(usize),
+}
+
+/// Computes the EIP-1167 minimal proxy creation code for an implementation.
+///
+/// See [`Clones#cloneDeterministic`].
+///
+/// [`Clones#cloneDeterministic`]: https://github.com/OpenZeppelin/openzeppelin-contracts/blob/v5.2.0/contracts/proxy/Clones.sol#L64
+pub fn creation_code_clone(implementation: Address) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(55);
+    bytes.extend_from_slice(CLONE_CREATION_HEAD);
+    bytes.extend_from_slice(CLONE_RUNTIME_HEAD);
+    bytes.extend_from_slice(implementation.as_slice());
+    bytes.extend_from_slice(CLONE_RUNTIME_TAIL);
+
+    bytes
+}
+
+/// Computes the creation code of a minimal proxy with immutable args appended to its runtime code.
+///
+/// See [`Clones#cloneDeterministicWithImmutableArgs`].
+///
+/// [`Clones#cloneDeterministicWithImmutableArgs`]: https://github.com/OpenZeppelin/openzeppelin-contracts/blob/v5.2.0/contracts/proxy/Clones.sol#L150
+pub fn creation_code_clone_with_immutable_args(
+    implementation: Address,
+    args: &[u8],
+) -> Result<Vec<u8>, CloneError> {
+    if args.len() > MAX_IMMUTABLE_ARGS_LENGTH {
+        return Err(CloneError::ArgumentsTooLong(args.len()));
+    }
+
+    let mut bytes = Vec::with_capacity(55 + args.len());
+    bytes.push(0x61);
+    bytes.extend_from_slice(&((args.len() + 45) as u16).to_be_bytes());
+    bytes.extend_from_slice(CLONE_WITH_ARGS_CREATION_HEAD);
+    bytes.extend_from_slice(CLONE_RUNTIME_HEAD);
+    bytes.extend_from_slice(implementation.as_slice());
+    bytes.extend_from_slice(CLONE_RUNTIME_TAIL);
+    bytes.extend_from_slice(args);
+
+    Ok(bytes)
+}
+
+/// Computes the address of a deterministic clone, as `Clones#predictDeterministicAddress` does.
+pub fn clone_address(deployer: Address, implementation: Address, salt: B256) -> Address {
+    let init_code_hash = keccak256(creation_code_clone(implementation));
+
+    create2_address(deployer, salt, init_code_hash)
+}
+
+/// Computes the address of a deterministic clone with immutable args, as
+/// `Clones#predictDeterministicAddressWithImmutableArgs` does.
+pub fn clone_with_immutable_args_address(
+    deployer: Address,
+    implementation: Address,
+    args: &[u8],
+    salt: B256,
+) -> Result<Address, CloneError> {
+    let init_code_hash = keccak256(creation_code_clone_with_immutable_args(
+        implementation,
+        args,
+    )?);
+
+    Ok(create2_address(deployer, salt, init_code_hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, hex};
+
+    const IMPLEMENTATION: Address = address!("bebebebebebebebebebebebebebebebebebebebe");
+
+    #[test]
+    fn test_creation_code_clone() {
+        // The reference bytecode from EIP-1167.
+        let expected = hex!(
+            "3d602d80600a3d3981f3363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3"
+        );
+        assert_eq!(creation_code_clone(IMPLEMENTATION), expected);
+    }
+
+    #[test]
+    fn test_creation_code_clone_with_immutable_args() {
+        let args = hex!("c0ffee");
+        let expected = hex!(
+            "6100303d81600a3d39f3363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3c0ffee"
+        );
+        assert_eq!(
+            creation_code_clone_with_immutable_args(IMPLEMENTATION, &args).unwrap(),
+            expected
+        );
+
+        let too_long = vec![0u8; MAX_IMMUTABLE_ARGS_LENGTH + 1];
+        assert_eq!(
+            creation_code_clone_with_immutable_args(IMPLEMENTATION, &too_long),
+            Err(CloneError::ArgumentsTooLong(MAX_IMMUTABLE_ARGS_LENGTH + 1))
+        );
+    }
+
+    #[test]
+    fn test_clone_address() {
+        // `Clones.predictDeterministicAddress(IMPLEMENTATION, salt, deployer)`.
+        let deployer = address!("5fbdb2315678afecb367f032d93f642f64180aa3");
+        let salt = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        assert_eq!(
+            clone_address(deployer, IMPLEMENTATION, salt),
+            address!("e7f08455c6e0f72819a122837fbe5962abac51a0"),
+            "EIP-1167 clone address mismatch"
+        );
+    }
+
+    #[test]
+    fn test_clone_with_immutable_args_address() {
+        // `Clones.predictDeterministicAddressWithImmutableArgs(IMPLEMENTATION, 0xc0ffee, salt,
+        // deployer)`.
+        let deployer = address!("5fbdb2315678afecb367f032d93f642f64180aa3");
+        let salt = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        assert_eq!(
+            clone_with_immutable_args_address(deployer, IMPLEMENTATION, &hex!("c0ffee"), salt),
+            Ok(address!("4fdbc919c4e652abcee05300ae4f595d51bde039")),
+            "Immutable args clone address mismatch"
+        );
+    }
+}
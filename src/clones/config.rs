@@ -0,0 +1,43 @@
+use crate::createx::{Create2Match, Create2Result};
+use alloy_primitives::{Address, Bytes};
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_serde;
+
+/// Configuration for the deterministic clone mining process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneConfig {
+    /// The contract calling `Clones.cloneDeterministic`.
+    pub deployer: Address,
+    /// The implementation the clone delegates to.
+    pub implementation: Address,
+    /// Immutable args appended to the clone's code, selecting
+    /// `Clones.cloneDeterministicWithImmutableArgs`.
+    #[serde(default)]
+    pub immutable_args: Option<Bytes>,
+    /// The maximum number of attempts before giving up.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_results: u64,
+    /// Seed for the random number generator.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_option_u128")
+    )]
+    pub seed: Option<u128>,
+}
+
+/// A single successful match from a clone mining operation.
+pub type CloneMatch = Create2Match;
+
+/// Result structure that includes clone matches and total iterations.
+pub type CloneResult = Create2Result;
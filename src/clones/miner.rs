@@ -0,0 +1,163 @@
+use crate::clones::compute::{
+    CloneError, creation_code_clone, creation_code_clone_with_immutable_args,
+};
+use crate::clones::config::{CloneConfig, CloneResult};
+use crate::createx::{Create2Config, mine_create2_salt};
+use crate::pattern::{contains_predicate, prefix_predicate, suffix_predicate};
+use alloy_primitives::{Address, keccak256};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Builds the CREATE2 mining configuration for the clone's creation code.
+pub fn clone_create2_config(config: &CloneConfig) -> Result<Create2Config, CloneError> {
+    let creation_code = match &config.immutable_args {
+        Some(args) => creation_code_clone_with_immutable_args(config.implementation, args)?,
+        None => creation_code_clone(config.implementation),
+    };
+
+    Ok(Create2Config {
+        deployer: config.deployer,
+        init_code_hash: keccak256(creation_code),
+        max_iterations: config.max_iterations,
+        max_results: config.max_results,
+        salt_template: None,
+        seed: config.seed,
+    })
+}
+
+/// Mines for a salt that produces a deterministic clone address satisfying the given predicate.
+///
+/// ## Arguments
+/// * `config` - A `CloneConfig` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `CloneResult` containing the found salts/addresses and total iterations.
+pub fn mine_clone_salt(config: &CloneConfig, predicate: &dyn Fn(Address) -> bool) -> CloneResult {
+    let create2_config = match clone_create2_config(config) {
+        Ok(create2_config) => create2_config,
+        Err(e) => panic!("Invalid config: {e}"),
+    };
+
+    mine_create2_salt(&create2_config, predicate)
+}
+
+/// Mines for a salt that produces a clone address with a specific prefix.
+pub fn mine_clone_salt_with_prefix(config: &CloneConfig, prefix: &[u8]) -> CloneResult {
+    mine_clone_salt(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a salt that produces a clone address with a specific suffix.
+pub fn mine_clone_salt_with_suffix(config: &CloneConfig, suffix: &[u8]) -> CloneResult {
+    mine_clone_salt(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a salt that produces a clone address containing a specific byte sequence.
+pub fn mine_clone_salt_with_contains(config: &CloneConfig, contains: &[u8]) -> CloneResult {
+    mine_clone_salt(config, &contains_predicate(contains))
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_clone_salt_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CloneConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    clone_create2_config(&config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_clone_salt_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_clone_salt_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CloneConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    clone_create2_config(&config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_clone_salt_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_clone_salt_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CloneConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    clone_create2_config(&config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_clone_salt_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clones::compute::clone_address;
+    use crate::createx::create2_address;
+    use alloy_primitives::{address, bytes};
+
+    const DEPLOYER: Address = address!("5fbdb2315678afecb367f032d93f642f64180aa3");
+    const IMPLEMENTATION: Address = address!("bebebebebebebebebebebebebebebebebebebebe");
+
+    #[test]
+    fn test_mine_clone_for_prefix() {
+        let config = CloneConfig {
+            deployer: DEPLOYER,
+            implementation: IMPLEMENTATION,
+            immutable_args: None,
+            max_iterations: 1_000_000,
+            max_results: 1,
+            seed: Some(1234),
+        };
+
+        let prefix = &[0xc1, 0x0e];
+        let mining_result = mine_clone_salt_with_prefix(&config, prefix);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find address starting with 0xc10e within {} iterations",
+            config.max_iterations
+        );
+
+        let result = &mining_result.results[0];
+        assert!(result.computed_address.starts_with(prefix));
+        assert_eq!(
+            result.computed_address,
+            clone_address(DEPLOYER, IMPLEMENTATION, result.salt),
+            "Address should match Clones.predictDeterministicAddress"
+        );
+    }
+
+    #[test]
+    fn test_mine_clone_with_immutable_args() {
+        let args = bytes!("000000000000000000000000000000000000000000000000000000000000002a");
+        let config = CloneConfig {
+            deployer: DEPLOYER,
+            implementation: IMPLEMENTATION,
+            immutable_args: Some(args.clone()),
+            max_iterations: 100_000,
+            max_results: 1,
+            seed: Some(1234),
+        };
+
+        let mining_result = mine_clone_salt(&config, &|addr| addr[0] == 0x00);
+        let result = &mining_result.results[0];
+
+        let init_code_hash =
+            keccak256(creation_code_clone_with_immutable_args(IMPLEMENTATION, &args).unwrap());
+        assert_eq!(
+            result.computed_address,
+            create2_address(DEPLOYER, result.salt, init_code_hash),
+            "Address should be derived from the creation code with immutable args"
+        );
+    }
+}
@@ -0,0 +1,13 @@
+pub mod compute;
+pub mod config;
+pub mod miner;
+
+pub use compute::{
+    CloneError, clone_address, clone_with_immutable_args_address, creation_code_clone,
+    creation_code_clone_with_immutable_args,
+};
+pub use config::{CloneConfig, CloneMatch, CloneResult};
+pub use miner::{
+    clone_create2_config, mine_clone_salt, mine_clone_salt_with_contains,
+    mine_clone_salt_with_prefix, mine_clone_salt_with_suffix,
+};
@@ -0,0 +1,403 @@
+use crate::createx::compute;
+use crate::createx::config::{
+    ConfigError, Create3Config, Create3Match, Create3Scheme, CreateXCreate2Config,
+    CreateXCreate2Match, resolve_guard,
+};
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use alloy_sol_types::{SolCall, sol};
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+sol! {
+    /// The subset of the CreateX interface used to deploy mined salts.
+    ///
+    /// See [`ICreateX`](https://github.com/pcaversaccio/createx/blob/23d8a42b8e922f134ef2fa9e9470dfc6cced5d2e/src/ICreateX.sol).
+    interface ICreateX {
+        struct Values {
+            uint256 constructorAmount;
+            uint256 initCallAmount;
+        }
+
+        function deployCreate2(bytes32 salt, bytes memory initCode) external payable returns (address newContract);
+        function deployCreate2AndInit(bytes32 salt, bytes memory initCode, bytes memory data, Values memory values, address refundAddress) external payable returns (address newContract);
+        function deployCreate2AndInit(bytes32 salt, bytes memory initCode, bytes memory data, Values memory values) external payable returns (address newContract);
+        function deployCreate3(bytes32 salt, bytes memory initCode) external payable returns (address newContract);
+        function deployCreate3AndInit(bytes32 salt, bytes memory initCode, bytes memory data, Values memory values, address refundAddress) external payable returns (address newContract);
+        function deployCreate3AndInit(bytes32 salt, bytes memory initCode, bytes memory data, Values memory values) external payable returns (address newContract);
+    }
+}
+
+/// Errors raised when a match cannot be deployed through CreateX as mined.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CalldataError {
+    #[error("init code hash {actual} differs from the mined init code hash {expected}")]
+    InitCodeHashMismatch { expected: B256, actual: B256 },
+    #[error("CreateX deployCreate3 only deploys salts mined for the CreateX scheme, not {0:?}")]
+    UnsupportedScheme(Create3Scheme),
+    #[error("match address {computed} differs from the address {expected} CreateX deploys to")]
+    AddressMismatch {
+        expected: Address,
+        computed: Address,
+    },
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+}
+
+/// Parameters for the `...AndInit` variants, which call the new contract right after deployment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateXInit {
+    /// The calldata for the initialization call.
+    pub data: Bytes,
+    /// The value forwarded to the constructor.
+    #[serde(default)]
+    pub constructor_amount: U256,
+    /// The value forwarded to the initialization call.
+    #[serde(default)]
+    pub init_call_amount: U256,
+    /// The address refunded with any remaining balance. Defaults to `msg.sender` on-chain.
+    #[serde(default)]
+    pub refund_address: Option<Address>,
+}
+
+impl CreateXInit {
+    fn values(&self) -> ICreateX::Values {
+        ICreateX::Values {
+            constructorAmount: self.constructor_amount,
+            initCallAmount: self.init_call_amount,
+        }
+    }
+}
+
+/// Encodes the CreateX calldata deploying `init_code` with a mined CREATE2 salt.
+///
+/// Only matches from `mine_createx_create2_salt` are accepted. A plain `Create2Match` holds the
+/// salt used verbatim by CREATE2, but `deployCreate2` always passes the salt through
+/// `CreateX#_guard` first, so it would deploy to a different address.
+///
+/// ## Arguments
+/// * `config` - The `CreateXCreate2Config` the salt was mined with.
+/// * `result` - The match returned by `mine_createx_create2_salt`.
+/// * `init_code` - The creation bytecode whose hash was mined against.
+/// * `init` - Optional initialization call, selecting `deployCreate2AndInit`.
+///
+/// ## Returns
+/// The calldata to send to the CreateX factory, or an error if the init code or match does not
+/// belong to the config.
+pub fn deploy_create2_calldata(
+    config: &CreateXCreate2Config,
+    result: &CreateXCreate2Match,
+    init_code: &[u8],
+    init: Option<&CreateXInit>,
+) -> Result<Bytes, CalldataError> {
+    let init_code_hash = keccak256(init_code);
+    if init_code_hash != config.init_code_hash {
+        return Err(CalldataError::InitCodeHashMismatch {
+            expected: config.init_code_hash,
+            actual: init_code_hash,
+        });
+    }
+    let (caller, chain_id) =
+        resolve_guard(config.sender_protection, config.cross_chain_protection)?;
+    let guarded_salt = compute::guarded_salt(result.salt, caller, chain_id);
+    check_address(
+        compute::create2_address(config.deployer, guarded_salt, init_code_hash),
+        result.computed_address,
+    )?;
+
+    let salt = result.salt;
+    let init_code = Bytes::copy_from_slice(init_code);
+    let calldata = match init {
+        None => ICreateX::deployCreate2Call {
+            salt,
+            initCode: init_code,
+        }
+        .abi_encode(),
+        Some(init) => match init.refund_address {
+            Some(refund_address) => ICreateX::deployCreate2AndInit_0Call {
+                salt,
+                initCode: init_code,
+                data: init.data.clone(),
+                values: init.values(),
+                refundAddress: refund_address,
+            }
+            .abi_encode(),
+            None => ICreateX::deployCreate2AndInit_1Call {
+                salt,
+                initCode: init_code,
+                data: init.data.clone(),
+                values: init.values(),
+            }
+            .abi_encode(),
+        },
+    };
+
+    Ok(calldata.into())
+}
+
+/// Encodes the CreateX calldata deploying `init_code` with a mined CREATE3 salt.
+///
+/// ## Arguments
+/// * `config` - The `Create3Config` the salt was mined with, which must use the CreateX scheme.
+/// * `result` - The match returned by `mine_create3_salt`.
+/// * `init_code` - The creation bytecode of the contract to deploy.
+/// * `init` - Optional initialization call, selecting `deployCreate3AndInit`.
+///
+/// ## Returns
+/// The calldata to send to the CreateX factory, or an error if the match was mined for another
+/// scheme or does not belong to the config.
+pub fn deploy_create3_calldata(
+    config: &Create3Config,
+    result: &Create3Match,
+    init_code: &[u8],
+    init: Option<&CreateXInit>,
+) -> Result<Bytes, CalldataError> {
+    if config.scheme != Create3Scheme::CreateX {
+        return Err(CalldataError::UnsupportedScheme(config.scheme.clone()));
+    }
+    let (caller, chain_id) =
+        resolve_guard(config.sender_protection, config.cross_chain_protection)?;
+    let guarded_salt = compute::guarded_salt(result.salt, caller, chain_id);
+    check_address(
+        compute::create3_address(config.deployer, guarded_salt),
+        result.computed_address,
+    )?;
+
+    let salt = result.salt;
+    let init_code = Bytes::copy_from_slice(init_code);
+    let calldata = match init {
+        None => ICreateX::deployCreate3Call {
+            salt,
+            initCode: init_code,
+        }
+        .abi_encode(),
+        Some(init) => match init.refund_address {
+            Some(refund_address) => ICreateX::deployCreate3AndInit_0Call {
+                salt,
+                initCode: init_code,
+                data: init.data.clone(),
+                values: init.values(),
+                refundAddress: refund_address,
+            }
+            .abi_encode(),
+            None => ICreateX::deployCreate3AndInit_1Call {
+                salt,
+                initCode: init_code,
+                data: init.data.clone(),
+                values: init.values(),
+            }
+            .abi_encode(),
+        },
+    };
+
+    Ok(calldata.into())
+}
+
+/// Checks that the mined address is the one CreateX deploys to.
+fn check_address(expected: Address, computed: Address) -> Result<(), CalldataError> {
+    if expected != computed {
+        return Err(CalldataError::AddressMismatch { expected, computed });
+    }
+    Ok(())
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_deploy_create2_calldata(
+    config: JsValue,
+    result: JsValue,
+    init_code: &[u8],
+    init: JsValue,
+) -> Result<JsValue, JsValue> {
+    let config: CreateXCreate2Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let result: CreateXCreate2Match =
+        from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let init: Option<CreateXInit> =
+        from_value(init).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let calldata = deploy_create2_calldata(&config, &result, init_code, init.as_ref())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&calldata).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_deploy_create3_calldata(
+    config: JsValue,
+    result: JsValue,
+    init_code: &[u8],
+    init: JsValue,
+) -> Result<JsValue, JsValue> {
+    let config: Create3Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let result: Create3Match = from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let init: Option<CreateXInit> =
+        from_value(init).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let calldata = deploy_create3_calldata(&config, &result, init_code, init.as_ref())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&calldata).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::createx::config::{CrossChainProtection, SenderProtection};
+    use crate::createx::miner::{mine_create3_salt, mine_createx_create2_salt};
+    use alloy_primitives::{address, bytes};
+
+    const CREATEX: Address = address!("ba5ed099633d3b313e4d5f7bdc1305d3c28ba5ed");
+    const CALLER: Address = address!("deadbeefdeadbeefdeadbeefdeadbeefdeadbeef");
+    const INIT_CODE: Bytes = bytes!("6080604052348015600f57600080fd5b50");
+
+    fn create2_config() -> CreateXCreate2Config {
+        CreateXCreate2Config {
+            deployer: CREATEX,
+            init_code_hash: keccak256(&INIT_CODE),
+            sender_protection: SenderProtection::Caller(CALLER),
+            cross_chain_protection: CrossChainProtection::Enabled(1),
+            max_iterations: 1,
+            max_results: 1,
+            salt_template: None,
+            seed: None,
+        }
+    }
+
+    fn create3_config() -> Create3Config {
+        Create3Config {
+            deployer: CREATEX,
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::Caller(CALLER),
+            cross_chain_protection: CrossChainProtection::Enabled(1),
+            max_iterations: 1,
+            max_results: 1,
+            salt_template: None,
+            seed: None,
+        }
+    }
+
+    fn selector(signature: &str) -> [u8; 4] {
+        keccak256(signature)[..4].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_deploy_create3_calldata() {
+        let config = create3_config();
+        let result = mine_create3_salt(&config, &|_| true).results.remove(0);
+
+        let calldata = deploy_create3_calldata(&config, &result, &INIT_CODE, None).unwrap();
+        assert_eq!(
+            calldata[..4],
+            selector("deployCreate3(bytes32,bytes)"),
+            "Selector mismatch for deployCreate3"
+        );
+
+        let decoded = ICreateX::deployCreate3Call::abi_decode(&calldata).unwrap();
+        assert_eq!(
+            decoded.salt, result.salt,
+            "Calldata must carry the raw salt"
+        );
+        assert_eq!(decoded.initCode, INIT_CODE, "Init code mismatch");
+    }
+
+    #[test]
+    fn test_deploy_and_init_calldata() {
+        let mut init = CreateXInit {
+            data: bytes!("8129fc1c"),
+            constructor_amount: U256::from(1),
+            init_call_amount: U256::from(2),
+            refund_address: None,
+        };
+
+        let config = create2_config();
+        let result = mine_createx_create2_salt(&config, &|_| true)
+            .results
+            .remove(0);
+        let calldata = deploy_create2_calldata(&config, &result, &INIT_CODE, Some(&init)).unwrap();
+        assert_eq!(
+            calldata[..4],
+            selector("deployCreate2AndInit(bytes32,bytes,bytes,(uint256,uint256))"),
+            "Selector mismatch for deployCreate2AndInit without refund address"
+        );
+
+        init.refund_address = Some(address!("3333333333333333333333333333333333333333"));
+        let config = create3_config();
+        let result = mine_create3_salt(&config, &|_| true).results.remove(0);
+        let calldata = deploy_create3_calldata(&config, &result, &INIT_CODE, Some(&init)).unwrap();
+        assert_eq!(
+            calldata[..4],
+            selector("deployCreate3AndInit(bytes32,bytes,bytes,(uint256,uint256),address)"),
+            "Selector mismatch for deployCreate3AndInit with refund address"
+        );
+
+        let decoded = ICreateX::deployCreate3AndInit_0Call::abi_decode(&calldata).unwrap();
+        assert_eq!(decoded.salt, result.salt);
+        assert_eq!(decoded.data, init.data);
+        assert_eq!(decoded.values.constructorAmount, U256::from(1));
+        assert_eq!(decoded.values.initCallAmount, U256::from(2));
+        assert_eq!(Some(decoded.refundAddress), init.refund_address);
+    }
+
+    #[test]
+    fn test_deploy_create2_calldata_rejects_mismatches() {
+        let config = create2_config();
+        let result = mine_createx_create2_salt(&config, &|_| true)
+            .results
+            .remove(0);
+
+        let other_init_code = bytes!("00");
+        assert_eq!(
+            deploy_create2_calldata(&config, &result, &other_init_code, None),
+            Err(CalldataError::InitCodeHashMismatch {
+                expected: config.init_code_hash,
+                actual: keccak256(&other_init_code),
+            })
+        );
+
+        let unguarded = CreateXCreate2Config {
+            sender_protection: SenderProtection::ZeroAddress,
+            cross_chain_protection: CrossChainProtection::Disabled,
+            ..config.clone()
+        };
+        assert!(
+            matches!(
+                deploy_create2_calldata(&unguarded, &result, &INIT_CODE, None),
+                Err(CalldataError::AddressMismatch { .. })
+            ),
+            "The salt flags must be the ones the salt was mined with"
+        );
+    }
+
+    #[test]
+    fn test_deploy_create3_calldata_rejects_mismatches() {
+        let config = create3_config();
+        let result = mine_create3_salt(&config, &|_| true).results.remove(0);
+
+        let solady = Create3Config {
+            scheme: Create3Scheme::Solady,
+            sender_protection: SenderProtection::ZeroAddress,
+            cross_chain_protection: CrossChainProtection::Disabled,
+            ..config.clone()
+        };
+        let solady_result = mine_create3_salt(&solady, &|_| true).results.remove(0);
+        assert_eq!(
+            deploy_create3_calldata(&solady, &solady_result, &INIT_CODE, None),
+            Err(CalldataError::UnsupportedScheme(Create3Scheme::Solady))
+        );
+
+        let mut tampered = result;
+        tampered.computed_address = Address::ZERO;
+        assert_eq!(
+            deploy_create3_calldata(&config, &tampered, &INIT_CODE, None),
+            Err(CalldataError::AddressMismatch {
+                expected: compute::create3_address(CREATEX, tampered.guarded_salt),
+                computed: Address::ZERO,
+            })
+        );
+    }
+}
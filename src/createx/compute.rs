@@ -1,14 +1,20 @@
-use alloy_primitives::{Address, B256, ChainId, U256, keccak256};
+use alloy_primitives::{Address, B256, ChainId, U256, b256, keccak256};
 
 /// The proxy initcode used in CreateX's CREATE3 pattern.
 ///
+/// The same proxy is used by Solady's, solmate's and 0xSequence's `CREATE3` libraries.
+///
 /// See [`CreateX#deployCreate3`].
 ///
 /// [`CreateX#deployCreate3`]: https://github.com/pcaversaccio/createx/blob/23d8a42b8e922f134ef2fa9e9470dfc6cced5d2e/src/CreateX.sol#L632
-const PROXY_INIT_CODE: &[u8] = &[
+pub const PROXY_INIT_CODE: &[u8] = &[
     0x67, 0x36, 0x3d, 0x3d, 0x37, 0x36, 0x3d, 0x34, 0xf0, 0x3d, 0x52, 0x60, 0x08, 0x60, 0x18, 0xf3,
 ];
 
+/// `keccak256(PROXY_INIT_CODE)`.
+pub const PROXY_INIT_CODE_HASH: B256 =
+    b256!("21c35dbe1b344a2488cf3321d6ce542f8e9f305544ff09e4993a62319a497c1f");
+
 /// Implements different safeguarding mechanisms depending on the encoded values in the salt.
 ///
 /// See [`CreateX#_guard`].
@@ -45,25 +51,69 @@ pub fn guarded_salt(salt: B256, caller: Option<Address>, chain_id: Option<ChainI
 
 /// Computes a CREATE3 address from a guarded salt and deployer address.
 pub fn create3_address(deployer: Address, guarded_salt: B256) -> Address {
-    let proxy_init_code_hash = keccak256(PROXY_INIT_CODE);
-
-    // 1 (0xff) + 20 (deployer) + 32 (salt) + 32 (hash)
-    let mut proxy_preimage = [0u8; 85];
-    proxy_preimage[0] = 0xff;
-    proxy_preimage[1..21].copy_from_slice(deployer.as_slice());
-    proxy_preimage[21..53].copy_from_slice(guarded_salt.as_slice());
-    proxy_preimage[53..85].copy_from_slice(proxy_init_code_hash.as_slice());
-    let proxy_address_hash = keccak256(proxy_preimage);
-    let proxy_address = Address::from_slice(&proxy_address_hash[12..]);
-
-    // 1 (0xd6) + 1 (0x94) + 20 (proxy_addr) + 1 (0x01)
-    let mut final_preimage = [0u8; 23];
-    final_preimage[0] = 0xd6;
-    final_preimage[1] = 0x94;
-    final_preimage[2..22].copy_from_slice(proxy_address.as_slice());
-    final_preimage[22] = 0x01;
-    let final_address_hash = keccak256(final_preimage);
-    Address::from_slice(&final_address_hash[12..])
+    create3_address_with_proxy(deployer, guarded_salt, PROXY_INIT_CODE_HASH, 1)
+}
+
+/// Computes a CREATE3 address for an arbitrary intermediate proxy.
+///
+/// The proxy is deployed via CREATE2 from `deployer` and then deploys the final contract via
+/// CREATE at `proxy_nonce`.
+pub fn create3_address_with_proxy(
+    deployer: Address,
+    salt: B256,
+    proxy_init_code_hash: B256,
+    proxy_nonce: u64,
+) -> Address {
+    let proxy_address = create2_address(deployer, salt, proxy_init_code_hash);
+    create_address(proxy_address, proxy_nonce)
+}
+
+/// Derives the salt ZeframLou's `CREATE3Factory` passes to CREATE3.
+///
+/// See [`CREATE3Factory#deploy`].
+///
+/// [`CREATE3Factory#deploy`]: https://github.com/ZeframLou/create3-factory/blob/18cfad8d118b25a5092cdfed6bea9c932ca5b6eb/src/CREATE3Factory.sol#L17
+pub fn zeframlou_salt(caller: Address, salt: B256) -> B256 {
+    // keccak256(abi.encodePacked(msg.sender, salt))
+    let mut preimage = [0u8; 52]; // 20 (address) + 32 (salt)
+    preimage[0..20].copy_from_slice(caller.as_slice());
+    preimage[20..52].copy_from_slice(salt.as_slice());
+    keccak256(preimage)
+}
+
+/// Computes a CREATE address from a sender address and its nonce.
+///
+/// The address is `keccak256(rlp([sender, nonce]))[12..]`, where the nonce is RLP-encoded as
+/// `0x80` for zero, a single byte below `0x80`, or a length-prefixed big-endian integer otherwise.
+pub fn create_address(sender: Address, nonce: u64) -> Address {
+    // 1 (list header) + 1 (0x94) + 20 (sender) + 1 (nonce header) + 8 (nonce)
+    let mut preimage = [0u8; 31];
+    preimage[1] = 0x94;
+    preimage[2..22].copy_from_slice(sender.as_slice());
+
+    let nonce_len = match nonce {
+        0 => {
+            preimage[22] = 0x80;
+            1
+        }
+        1..=0x7f => {
+            preimage[22] = nonce as u8;
+            1
+        }
+        _ => {
+            let bytes = nonce.to_be_bytes();
+            let skip = nonce.leading_zeros() as usize / 8;
+            let len = 8 - skip;
+            preimage[22] = 0x80 + len as u8;
+            preimage[23..23 + len].copy_from_slice(&bytes[skip..]);
+            1 + len
+        }
+    };
+    let payload_len = 21 + nonce_len;
+    preimage[0] = 0xc0 + payload_len as u8;
+
+    let hash = keccak256(&preimage[..1 + payload_len]);
+    Address::from_slice(&hash[12..])
 }
 
 /// Computes a CREATE2 address from a deployer address, salt, and init code hash.
@@ -106,6 +156,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_proxy_init_code_hash() {
+        assert_eq!(keccak256(PROXY_INIT_CODE), PROXY_INIT_CODE_HASH);
+    }
+
+    #[test]
+    fn test_create3_address_with_proxy_nonce() {
+        let deployer = address!("ba5ed099633d3b313e4d5f7bdc1305d3c28ba5ed");
+        let salt = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        let proxy = create2_address(deployer, salt, PROXY_INIT_CODE_HASH);
+        assert_eq!(
+            create3_address_with_proxy(deployer, salt, PROXY_INIT_CODE_HASH, 1),
+            create3_address(deployer, salt),
+        );
+        assert_eq!(
+            create3_address_with_proxy(deployer, salt, PROXY_INIT_CODE_HASH, 2),
+            create_address(proxy, 2),
+        );
+    }
+
     #[test]
     fn test_create2_address() {
         let deployer = address!("5c69bee701ef814a2b6a3edd4b1652cb9cc5aa6f");
@@ -122,4 +193,35 @@ mod tests {
             deployer, salt
         );
     }
+
+    #[test]
+    fn test_create_address_nonce_sizes() {
+        let sender = address!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+        let cases = [
+            (0, address!("cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d")),
+            (1, address!("343c43a37d37dff08ae8c4a11544c718abb4fcf8")),
+            (0x7f, address!("06d9a77f5e4b311bae8d559db9cdb4df94104aa0")),
+            (0x80, address!("08e190dcb7b73f5fcdabb43e102215c83659a76d")),
+            (0xff, address!("3ef7c1a519e4b4431e317d7839340e3139b03c65")),
+            (0x100, address!("3837c1ae70354f670550c746580199ac6a73cb0a")),
+            (
+                0xffff_ffff,
+                address!("4c9958390a81acc68a5f19aa8e6188bebbbeefd7"),
+            ),
+            (
+                u64::MAX,
+                address!("9bc924993b60399df164c3763a964301d3db95ca"),
+            ),
+        ];
+
+        for (nonce, expected_address) in cases {
+            assert_eq!(
+                create_address(sender, nonce),
+                expected_address,
+                "CREATE address computation mismatch for sender {} with nonce {}",
+                sender,
+                nonce
+            );
+        }
+    }
 }
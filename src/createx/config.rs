@@ -1,36 +1,361 @@
-use alloy_primitives::{Address, B256, ChainId};
+use crate::createx::compute::{self, PROXY_INIT_CODE};
+use crate::createx::template::{SaltTemplate, SaltTemplateError};
+use alloy_primitives::{Address, B256, Bytes, ChainId};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 #[cfg(target_arch = "wasm32")]
 use crate::wasm_serde;
 
-/// Configuration for the Create3 mining process via CreateX.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// The sender bytes (salt bytes 0..20) as interpreted by `CreateX#_parseSalt`.
+///
+/// See [`CreateX#_parseSalt`].
+///
+/// [`CreateX#_parseSalt`]: https://github.com/pcaversaccio/createx/blob/23d8a42b8e922f134ef2fa9e9470dfc6cced5d2e/src/CreateX.sol#L934
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SenderProtection {
+    /// The salt starts with `msg.sender`, so only that caller can deploy to the address.
+    Caller(Address),
+    /// The salt starts with the zero address, so anyone can deploy to the address.
+    #[default]
+    ZeroAddress,
+    /// The salt starts with an arbitrary prefix that is not the deploying caller. CreateX then
+    /// ignores the redeploy protection flag entirely.
+    Random(Address),
+}
+
+impl SenderProtection {
+    /// The 20 bytes written at the start of the salt.
+    pub fn salt_prefix(&self) -> Address {
+        match self {
+            Self::Caller(address) | Self::Random(address) => *address,
+            Self::ZeroAddress => Address::ZERO,
+        }
+    }
+}
+
+/// The redeploy protection flag (salt byte 20) as interpreted by `CreateX#_parseSalt`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrossChainProtection {
+    /// `0x01`: the chain id is mixed into the guarded salt.
+    Enabled(ChainId),
+    /// `0x00`: the address is the same on every chain.
+    #[default]
+    Disabled,
+    /// Any other flag value. CreateX reverts unless the sender bytes are random.
+    Unspecified(u8),
+}
+
+impl CrossChainProtection {
+    /// The byte written at salt index 20.
+    pub fn salt_flag(&self) -> u8 {
+        match self {
+            Self::Enabled(_) => 0x01,
+            Self::Disabled => 0x00,
+            Self::Unspecified(flag) => *flag,
+        }
+    }
+}
+
+/// Resolves the `CreateX#_guard` branch taken for the given salt flags.
+///
+/// ## Returns
+/// The `(caller, chain_id)` pair to pass to `compute::guarded_salt`, or an error if CreateX would
+/// revert on the combination.
+pub fn resolve_guard(
+    sender: SenderProtection,
+    cross_chain: CrossChainProtection,
+) -> Result<(Option<Address>, Option<ChainId>), ConfigError> {
+    match (sender, cross_chain) {
+        (_, CrossChainProtection::Unspecified(flag @ (0x00 | 0x01))) => {
+            Err(ConfigError::AmbiguousFlag(flag))
+        }
+        (SenderProtection::Caller(caller), _) if caller.is_zero() => {
+            Err(ConfigError::ZeroAddressCaller)
+        }
+        (SenderProtection::Random(prefix), _) if prefix.is_zero() => {
+            Err(ConfigError::ZeroAddressRandomPrefix)
+        }
+        (SenderProtection::Caller(caller), CrossChainProtection::Enabled(id)) => {
+            Ok((Some(caller), Some(id)))
+        }
+        (SenderProtection::Caller(caller), CrossChainProtection::Disabled) => {
+            Ok((Some(caller), None))
+        }
+        (SenderProtection::ZeroAddress, CrossChainProtection::Enabled(id)) => Ok((None, Some(id))),
+        (SenderProtection::ZeroAddress, CrossChainProtection::Disabled) => Ok((None, None)),
+        (
+            SenderProtection::Caller(_) | SenderProtection::ZeroAddress,
+            CrossChainProtection::Unspecified(flag),
+        ) => Err(ConfigError::InvalidSalt { flag }),
+        // Random sender bytes fall through to `keccak256(abi.encode(salt))` regardless of the flag.
+        (SenderProtection::Random(_), _) => Ok((None, None)),
+    }
+}
+
+/// Returns the salt bytes 0..21 that CreateX interprets as sender bytes and redeploy flag.
+pub fn createx_salt_prefix(
+    sender: SenderProtection,
+    cross_chain: CrossChainProtection,
+) -> [u8; 21] {
+    let mut reserved = [0u8; 21];
+    reserved[0..20].copy_from_slice(sender.salt_prefix().as_slice());
+    reserved[20] = cross_chain.salt_flag();
+    reserved
+}
+
+/// Resolves the salt flags of a deserialized config, mapping the legacy `caller` field to
+/// `SenderProtection::Caller` and `chain_id` to `CrossChainProtection::Enabled`.
+pub(crate) fn resolve_legacy_protection(
+    sender_protection: Option<SenderProtection>,
+    cross_chain_protection: Option<CrossChainProtection>,
+    caller: Option<Address>,
+    chain_id: Option<ChainId>,
+) -> Result<(SenderProtection, CrossChainProtection), ConfigError> {
+    let sender = match (sender_protection, caller) {
+        (Some(_), Some(_)) => {
+            return Err(ConfigError::ConflictingProtection {
+                legacy: "caller",
+                replacement: "sender_protection",
+            });
+        }
+        (Some(sender), None) => sender,
+        (None, Some(caller)) => SenderProtection::Caller(caller),
+        (None, None) => SenderProtection::default(),
+    };
+    let cross_chain = match (cross_chain_protection, chain_id) {
+        (Some(_), Some(_)) => {
+            return Err(ConfigError::ConflictingProtection {
+                legacy: "chain_id",
+                replacement: "cross_chain_protection",
+            });
+        }
+        (Some(cross_chain), None) => cross_chain,
+        (None, Some(chain_id)) => CrossChainProtection::Enabled(chain_id),
+        (None, None) => CrossChainProtection::default(),
+    };
+
+    Ok((sender, cross_chain))
+}
+
+/// Describes the intermediate proxy a CREATE3 implementation deploys via CREATE2.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Create3Proxy {
+    /// The creation code of the proxy.
+    pub init_code: Bytes,
+    /// The nonce of the proxy when it deploys the final contract via CREATE.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub nonce: u64,
+}
+
+impl Create3Proxy {
+    /// The 16-byte proxy shared by CreateX, Solady, solmate and 0xSequence.
+    pub const fn minimal() -> Self {
+        Self {
+            init_code: Bytes::from_static(PROXY_INIT_CODE),
+            nonce: 1,
+        }
+    }
+}
+
+/// The CREATE3 implementation the salt is mined for.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Create3Scheme {
+    /// CreateX's `deployCreate3`, which applies `CreateX#_guard` to the salt.
+    #[default]
+    CreateX,
+    /// Solady's `CREATE3` library, which uses the salt as-is.
+    ///
+    /// See [`CREATE3`](https://github.com/Vectorized/solady/blob/main/src/utils/CREATE3.sol).
+    Solady,
+    /// 0xSequence's `Create3` library, which uses the salt as-is.
+    ///
+    /// See [`Create3`](https://github.com/0xsequence/create3/blob/master/contracts/Create3.sol).
+    Sequence,
+    /// ZeframLou's `CREATE3Factory`, which namespaces the salt by the calling account.
+    ZeframLou {
+        /// The account calling `CREATE3Factory#deploy`.
+        caller: Address,
+    },
+    /// Any other CREATE3 implementation that uses the salt as-is.
+    Custom(Create3Proxy),
+}
+
+impl Create3Scheme {
+    /// Returns the proxy deployed by the scheme.
+    pub fn proxy(&self) -> Create3Proxy {
+        match self {
+            Self::Custom(proxy) => proxy.clone(),
+            _ => Create3Proxy::minimal(),
+        }
+    }
+
+    /// Derives the salt used to CREATE2 the proxy.
+    ///
+    /// `caller` and `chain_id` are the `CreateX#_guard` inputs from `resolve_guard`, which are
+    /// ignored by every other scheme.
+    pub fn proxy_salt(
+        &self,
+        salt: B256,
+        caller: Option<Address>,
+        chain_id: Option<ChainId>,
+    ) -> B256 {
+        match self {
+            Self::CreateX => compute::guarded_salt(salt, caller, chain_id),
+            Self::ZeframLou { caller } => compute::zeframlou_salt(*caller, salt),
+            Self::Solady | Self::Sequence | Self::Custom(_) => salt,
+        }
+    }
+}
+
+/// Errors raised when a mining configuration cannot produce a deployable salt.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConfigError {
+    #[error(
+        "CreateX reverts with `InvalidSalt` for redeploy protection flag {flag:#04x} unless the sender bytes are random"
+    )]
+    InvalidSalt { flag: u8 },
+    #[error("unspecified redeploy protection flag must not be 0x00 or 0x01, got {0:#04x}")]
+    AmbiguousFlag(u8),
+    #[error("the caller for permissioned deploy protection cannot be the zero address")]
+    ZeroAddressCaller,
+    #[error("a random sender prefix cannot be the zero address")]
+    ZeroAddressRandomPrefix,
+    #[error("salt protection flags are only interpreted by CreateX")]
+    UnsupportedProtection,
+    #[error("legacy `{legacy}` cannot be combined with `{replacement}`, which replaces it")]
+    ConflictingProtection {
+        legacy: &'static str,
+        replacement: &'static str,
+    },
+    #[error("unknown CREATE2 deployer preset `{0}`")]
+    UnknownPreset(String),
+    #[error(transparent)]
+    SaltTemplate(#[from] SaltTemplateError),
+}
+
+/// Configuration for the Create3 mining process, via CreateX by default.
+///
+/// The `caller` and `chain_id` fields of earlier releases are still accepted and mapped to
+/// `sender_protection` and `cross_chain_protection`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "Create3ConfigRepr")]
 pub struct Create3Config {
-    /// The address of the CreateX factory contract.
+    /// The address of the contract deploying the CREATE3 proxy, e.g. the CreateX factory.
     pub deployer: Address,
-    /// Permissioned deploy protection for that address.
-    pub caller: Option<Address>,
-    /// Cross-chain deployment protection.
-    pub chain_id: Option<ChainId>,
+    /// The CREATE3 implementation to mine for.
+    pub scheme: Create3Scheme,
+    /// Permissioned deploy protection encoded in salt bytes 0..20. CreateX only.
+    pub sender_protection: SenderProtection,
+    /// Cross-chain redeploy protection encoded in salt byte 20. CreateX only.
+    pub cross_chain_protection: CrossChainProtection,
     /// The maximum number of attempts before giving up.
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    pub max_results: u64,
+    /// Optional layout of fixed and mined salt bytes.
+    pub salt_template: Option<SaltTemplate>,
+    /// Seed for the random number generator.
+    pub seed: Option<u128>,
+}
+
+/// The serialized form of `Create3Config`, including the legacy salt flag fields.
+#[derive(Deserialize)]
+struct Create3ConfigRepr {
+    deployer: Address,
+    #[serde(default)]
+    scheme: Create3Scheme,
+    #[serde(default)]
+    sender_protection: Option<SenderProtection>,
+    #[serde(default)]
+    cross_chain_protection: Option<CrossChainProtection>,
+    #[serde(default)]
+    caller: Option<Address>,
+    #[serde(default)]
+    chain_id: Option<ChainId>,
     #[cfg_attr(
         target_arch = "wasm32",
         serde(deserialize_with = "wasm_serde::deserialize_u64")
     )]
-    pub max_iterations: u64,
-    /// The maximum number of results to find.
+    max_iterations: u64,
     #[cfg_attr(
         target_arch = "wasm32",
         serde(deserialize_with = "wasm_serde::deserialize_u64")
     )]
-    pub max_results: u64,
-    /// Seed for the random number generator.
+    max_results: u64,
+    #[serde(default)]
+    salt_template: Option<SaltTemplate>,
     #[cfg_attr(
         target_arch = "wasm32",
         serde(deserialize_with = "wasm_serde::deserialize_option_u128")
     )]
-    pub seed: Option<u128>,
+    seed: Option<u128>,
+}
+
+impl TryFrom<Create3ConfigRepr> for Create3Config {
+    type Error = ConfigError;
+
+    fn try_from(repr: Create3ConfigRepr) -> Result<Self, Self::Error> {
+        let (sender_protection, cross_chain_protection) = resolve_legacy_protection(
+            repr.sender_protection,
+            repr.cross_chain_protection,
+            repr.caller,
+            repr.chain_id,
+        )?;
+
+        Ok(Self {
+            deployer: repr.deployer,
+            scheme: repr.scheme,
+            sender_protection,
+            cross_chain_protection,
+            max_iterations: repr.max_iterations,
+            max_results: repr.max_results,
+            salt_template: repr.salt_template,
+            seed: repr.seed,
+        })
+    }
+}
+
+impl Create3Config {
+    /// Returns the leading salt bytes written by the scheme itself.
+    ///
+    /// For CreateX these are bytes 0..21 holding the caller and chain flags; other schemes
+    /// reserve nothing.
+    pub fn reserved_salt_bytes(&self) -> Vec<u8> {
+        match self.scheme {
+            Create3Scheme::CreateX => {
+                createx_salt_prefix(self.sender_protection, self.cross_chain_protection).to_vec()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the salt template to mine with, defaulting to mining every unreserved byte.
+    pub fn effective_salt_template(&self) -> SaltTemplate {
+        self.salt_template
+            .clone()
+            .unwrap_or_else(|| SaltTemplate::mined_after(self.reserved_salt_bytes().len() as u8))
+    }
+
+    /// Validates the salt flags and template against the scheme's salt layout.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.scheme == Create3Scheme::CreateX {
+            resolve_guard(self.sender_protection, self.cross_chain_protection)?;
+        } else if self.sender_protection != SenderProtection::default()
+            || self.cross_chain_protection != CrossChainProtection::default()
+        {
+            return Err(ConfigError::UnsupportedProtection);
+        }
+        self.effective_salt_template()
+            .validate(&self.reserved_salt_bytes(), self.max_iterations)?;
+        Ok(())
+    }
 }
 
 /// A single successful match from a Create3 mining operation.
@@ -58,9 +383,9 @@ pub struct Create3Result {
 }
 
 /// Configuration for the Create2 mining process
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Create2Config {
-    /// The address of the CreateX factory contract.
+    /// The address of the contract executing CREATE2.
     pub deployer: Address,
     /// The init code hash to use for the CREATE2 address.
     pub init_code_hash: B256,
@@ -76,6 +401,9 @@ pub struct Create2Config {
         serde(deserialize_with = "wasm_serde::deserialize_u64")
     )]
     pub max_results: u64,
+    /// Optional layout of fixed and mined salt bytes.
+    #[serde(default)]
+    pub salt_template: Option<SaltTemplate>,
     /// Seed for the random number generator.
     #[cfg_attr(
         target_arch = "wasm32",
@@ -84,6 +412,22 @@ pub struct Create2Config {
     pub seed: Option<u128>,
 }
 
+impl Create2Config {
+    /// Returns the salt template to mine with, defaulting to mining the whole salt.
+    pub fn effective_salt_template(&self) -> SaltTemplate {
+        self.salt_template
+            .clone()
+            .unwrap_or_else(SaltTemplate::fully_mined)
+    }
+
+    /// Validates the salt template.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.effective_salt_template()
+            .validate(&[], self.max_iterations)?;
+        Ok(())
+    }
+}
+
 /// A single successful match from a Create2 mining operation.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Create2Match {
@@ -105,3 +449,303 @@ pub struct Create2Result {
     )]
     pub total_iterations: usize,
 }
+
+/// Configuration for the Create2 mining process via CreateX's `deployCreate2`.
+///
+/// The `caller` and `chain_id` fields of earlier releases are still accepted and mapped to
+/// `sender_protection` and `cross_chain_protection`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "CreateXCreate2ConfigRepr")]
+pub struct CreateXCreate2Config {
+    /// The address of the CreateX factory contract.
+    pub deployer: Address,
+    /// The init code hash to use for the CREATE2 address.
+    pub init_code_hash: B256,
+    /// Permissioned deploy protection encoded in salt bytes 0..20.
+    pub sender_protection: SenderProtection,
+    /// Cross-chain redeploy protection encoded in salt byte 20.
+    pub cross_chain_protection: CrossChainProtection,
+    /// The maximum number of attempts before giving up.
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    pub max_results: u64,
+    /// Optional layout of fixed and mined salt bytes.
+    pub salt_template: Option<SaltTemplate>,
+    /// Seed for the random number generator.
+    pub seed: Option<u128>,
+}
+
+/// The serialized form of `CreateXCreate2Config`, including the legacy salt flag fields.
+#[derive(Deserialize)]
+struct CreateXCreate2ConfigRepr {
+    deployer: Address,
+    init_code_hash: B256,
+    #[serde(default)]
+    sender_protection: Option<SenderProtection>,
+    #[serde(default)]
+    cross_chain_protection: Option<CrossChainProtection>,
+    #[serde(default)]
+    caller: Option<Address>,
+    #[serde(default)]
+    chain_id: Option<ChainId>,
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    max_iterations: u64,
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    max_results: u64,
+    #[serde(default)]
+    salt_template: Option<SaltTemplate>,
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_option_u128")
+    )]
+    seed: Option<u128>,
+}
+
+impl TryFrom<CreateXCreate2ConfigRepr> for CreateXCreate2Config {
+    type Error = ConfigError;
+
+    fn try_from(repr: CreateXCreate2ConfigRepr) -> Result<Self, Self::Error> {
+        let (sender_protection, cross_chain_protection) = resolve_legacy_protection(
+            repr.sender_protection,
+            repr.cross_chain_protection,
+            repr.caller,
+            repr.chain_id,
+        )?;
+
+        Ok(Self {
+            deployer: repr.deployer,
+            init_code_hash: repr.init_code_hash,
+            sender_protection,
+            cross_chain_protection,
+            max_iterations: repr.max_iterations,
+            max_results: repr.max_results,
+            salt_template: repr.salt_template,
+            seed: repr.seed,
+        })
+    }
+}
+
+impl CreateXCreate2Config {
+    /// Returns the salt bytes 0..21 that CreateX interprets as caller and chain flags.
+    pub fn reserved_salt_bytes(&self) -> [u8; 21] {
+        createx_salt_prefix(self.sender_protection, self.cross_chain_protection)
+    }
+
+    /// Returns the salt template to mine with, defaulting to mining bytes 21..32.
+    pub fn effective_salt_template(&self) -> SaltTemplate {
+        self.salt_template
+            .clone()
+            .unwrap_or_else(|| SaltTemplate::mined_after(21))
+    }
+
+    /// Validates the salt flags and template against the CreateX salt layout.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        resolve_guard(self.sender_protection, self.cross_chain_protection)?;
+        self.effective_salt_template()
+            .validate(&self.reserved_salt_bytes(), self.max_iterations)?;
+        Ok(())
+    }
+}
+
+/// A single successful match from a CreateX Create2 mining operation.
+pub type CreateXCreate2Match = Create3Match;
+
+/// Result structure that includes CreateX Create2 matches and total iterations.
+pub type CreateXCreate2Result = Create3Result;
+
+/// Configuration for the CREATE (nonce-based) mining process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateConfig {
+    /// The account executing CREATE.
+    pub sender: Address,
+    /// The first nonce to try, usually the current nonce of the sender.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub start_nonce: u64,
+    /// The maximum number of nonces to try before giving up.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_results: u64,
+}
+
+/// A single successful match from a CREATE mining operation.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateMatch {
+    /// The nonce at which the sender deploys to the computed address.
+    pub nonce: u64,
+    /// The final, computed contract address.
+    pub computed_address: Address,
+}
+
+/// Result structure that includes CREATE matches and total iterations.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateResult {
+    /// The found matches.
+    pub results: Vec<CreateMatch>,
+    /// Total number of iterations performed.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(serialize_with = "crate::wasm_serde::serialize_usize")
+    )]
+    pub total_iterations: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const CALLER: Address = address!("DeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF");
+
+    #[test]
+    fn test_resolve_guard_matrix() {
+        let random = SenderProtection::Random(address!("1111111111111111111111111111111111111111"));
+        let cases = [
+            (
+                SenderProtection::Caller(CALLER),
+                CrossChainProtection::Enabled(1),
+                Ok((Some(CALLER), Some(1))),
+            ),
+            (
+                SenderProtection::Caller(CALLER),
+                CrossChainProtection::Disabled,
+                Ok((Some(CALLER), None)),
+            ),
+            (
+                SenderProtection::Caller(CALLER),
+                CrossChainProtection::Unspecified(0x02),
+                Err(ConfigError::InvalidSalt { flag: 0x02 }),
+            ),
+            (
+                SenderProtection::ZeroAddress,
+                CrossChainProtection::Enabled(1),
+                Ok((None, Some(1))),
+            ),
+            (
+                SenderProtection::ZeroAddress,
+                CrossChainProtection::Disabled,
+                Ok((None, None)),
+            ),
+            (
+                SenderProtection::ZeroAddress,
+                CrossChainProtection::Unspecified(0xff),
+                Err(ConfigError::InvalidSalt { flag: 0xff }),
+            ),
+            (random, CrossChainProtection::Enabled(1), Ok((None, None))),
+            (random, CrossChainProtection::Disabled, Ok((None, None))),
+            (
+                random,
+                CrossChainProtection::Unspecified(0x02),
+                Ok((None, None)),
+            ),
+            (
+                random,
+                CrossChainProtection::Unspecified(0x01),
+                Err(ConfigError::AmbiguousFlag(0x01)),
+            ),
+            (
+                SenderProtection::Caller(Address::ZERO),
+                CrossChainProtection::Disabled,
+                Err(ConfigError::ZeroAddressCaller),
+            ),
+            (
+                SenderProtection::Random(Address::ZERO),
+                CrossChainProtection::Disabled,
+                Err(ConfigError::ZeroAddressRandomPrefix),
+            ),
+        ];
+
+        for (sender, cross_chain, expected) in cases {
+            assert_eq!(
+                resolve_guard(sender, cross_chain),
+                expected,
+                "Guard branch mismatch for {:?} with {:?}",
+                sender,
+                cross_chain
+            );
+        }
+    }
+
+    #[test]
+    fn test_reserved_salt_bytes() {
+        let config = Create3Config {
+            deployer: Address::ZERO,
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::Caller(CALLER),
+            cross_chain_protection: CrossChainProtection::Unspecified(0x7f),
+            max_iterations: 1,
+            max_results: 1,
+            salt_template: None,
+            seed: None,
+        };
+
+        let reserved = config.reserved_salt_bytes();
+        assert_eq!(&reserved[0..20], CALLER.as_slice());
+        assert_eq!(reserved[20], 0x7f);
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::InvalidSalt { flag: 0x7f }),
+            "CreateX reverts for a caller prefix with an unspecified flag"
+        );
+    }
+
+    #[test]
+    fn test_configs_accept_legacy_caller_and_chain_id() {
+        let legacy = serde_json::json!({
+            "deployer": "0xba5ed099633d3b313e4d5f7bdc1305d3c28ba5ed",
+            "init_code_hash": B256::ZERO,
+            "caller": CALLER,
+            "chain_id": 1,
+            "max_iterations": 1,
+            "max_results": 1,
+            "seed": null
+        });
+
+        let create3: Create3Config = serde_json::from_value(legacy.clone()).unwrap();
+        assert_eq!(create3.sender_protection, SenderProtection::Caller(CALLER));
+        assert_eq!(
+            create3.cross_chain_protection,
+            CrossChainProtection::Enabled(1)
+        );
+
+        let createx_create2: CreateXCreate2Config = serde_json::from_value(legacy).unwrap();
+        assert_eq!(
+            createx_create2.sender_protection,
+            SenderProtection::Caller(CALLER)
+        );
+        assert_eq!(
+            createx_create2.cross_chain_protection,
+            CrossChainProtection::Enabled(1)
+        );
+
+        let conflicting = serde_json::json!({
+            "deployer": "0xba5ed099633d3b313e4d5f7bdc1305d3c28ba5ed",
+            "caller": CALLER,
+            "sender_protection": "zero_address",
+            "max_iterations": 1,
+            "max_results": 1,
+            "seed": null
+        });
+        let err = serde_json::from_value::<Create3Config>(conflicting).unwrap_err();
+        assert!(
+            err.to_string().contains("legacy `caller`"),
+            "Legacy and new salt flags must not be combined, got: {err}"
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use crate::createx::compute;
 use crate::createx::config::{
     Create2Config, Create2Match, Create2Result, Create3Config, Create3Match, Create3Result,
+    CreateConfig, CreateMatch, CreateResult, CreateXCreate2Config, CreateXCreate2Match,
+    CreateXCreate2Result, resolve_guard,
 };
-use alloy_primitives::{Address, B256, keccak256};
+use crate::pattern::{contains_predicate, prefix_predicate, suffix_predicate};
+use alloy_primitives::{Address, keccak256};
 #[cfg(target_arch = "wasm32")]
 use serde_wasm_bindgen::{from_value, to_value};
 #[cfg(target_arch = "wasm32")]
@@ -22,15 +25,19 @@ pub fn mine_create2_salt(
     config: &Create2Config,
     predicate: &dyn Fn(Address) -> bool,
 ) -> Create2Result {
-    let seed = config.seed.unwrap_or(DEFAULT_SEED).to_be_bytes();
-    let mut salt = B256::from(keccak256(seed).0);
+    if let Err(e) = config.validate() {
+        panic!("Invalid config: {e}");
+    }
+    let mut cursor = config
+        .effective_salt_template()
+        .cursor(config.seed.unwrap_or(DEFAULT_SEED));
     let mut results = Vec::new();
 
     let mut total_iterations = 0;
 
     for i in 0..config.max_iterations {
         total_iterations = (i + 1) as usize;
-        increment_b256_in_place(&mut salt);
+        let salt = cursor.next_salt();
 
         let computed_address =
             compute::create2_address(config.deployer, salt, config.init_code_hash);
@@ -55,36 +62,24 @@ pub fn mine_create2_salt(
 
 /// Mines for a salt that produces a CREATE2 address with a specific prefix.
 pub fn mine_create2_salt_with_prefix(config: &Create2Config, prefix: &[u8]) -> Create2Result {
-    if prefix.len() > 20 {
-        panic!("Prefix cannot be longer than 20 bytes");
-    }
-    let predicate = |addr: Address| addr.starts_with(prefix);
-    mine_create2_salt(config, &predicate)
+    mine_create2_salt(config, &prefix_predicate(prefix))
 }
 
 /// Mines for a salt that produces a CREATE2 address with a specific suffix.
 pub fn mine_create2_salt_with_suffix(config: &Create2Config, suffix: &[u8]) -> Create2Result {
-    if suffix.len() > 20 {
-        panic!("Suffix cannot be longer than 20 bytes");
-    }
-    let predicate = |addr: Address| addr.ends_with(suffix);
-    mine_create2_salt(config, &predicate)
+    mine_create2_salt(config, &suffix_predicate(suffix))
 }
 
 /// Mines for a salt that produces a CREATE2 address containing a specific byte sequence.
 pub fn mine_create2_salt_with_contains(config: &Create2Config, contains: &[u8]) -> Create2Result {
-    if contains.len() > 20 {
-        panic!("Contained sequence cannot be longer than 20 bytes");
-    }
-    let predicate = |addr: Address| {
-        addr.windows(contains.len())
-            .any(|window| window == contains)
-    };
-    mine_create2_salt(config, &predicate)
+    mine_create2_salt(config, &contains_predicate(contains))
 }
 
 /// Mines for a salt that produces a CREATE3 address satisfying the given predicate.
 ///
+/// Without a `salt_template`, the bytes after the scheme's reserved prefix hold `seed + i` on
+/// iteration `i`, so the same seed always yields the same salts.
+///
 /// ## Arguments
 /// * `config` - A `Create3Config` struct defining the mining parameters.
 /// * `predicate` - A closure that returns true if the computed address is the desired one.
@@ -95,33 +90,37 @@ pub fn mine_create3_salt(
     config: &Create3Config,
     predicate: &dyn Fn(Address) -> bool,
 ) -> Create3Result {
-    let mut salt_bytes = [0u8; 32];
-    let mut results = Vec::new();
-
-    // Permissioned protection
-    if let Some(caller) = config.caller {
-        salt_bytes[0..20].copy_from_slice(caller.as_slice());
-    }
-
-    // Cross-chain protection
-    if config.chain_id.is_some() {
-        salt_bytes[20] = 0x01;
+    if let Err(e) = config.validate() {
+        panic!("Invalid config: {e}");
     }
-
+    let template = config.effective_salt_template();
     let seed = config.seed.unwrap_or(DEFAULT_SEED);
+    // Without a template the counter starts at the seed itself, as it always has, so saved seeds
+    // reproduce the same salts.
+    let mut cursor = match config.salt_template {
+        Some(_) => template.cursor(seed),
+        None => template.sequential_cursor(seed),
+    };
+    cursor.write_reserved(&config.reserved_salt_bytes());
+    let (caller, chain_id) = resolve_guard(config.sender_protection, config.cross_chain_protection)
+        .expect("validated above");
+    let proxy = config.scheme.proxy();
+    let proxy_init_code_hash = keccak256(&proxy.init_code);
+    let mut results = Vec::new();
+
     let mut total_iterations = 0;
 
     for i in 0..config.max_iterations {
         total_iterations = (i + 1) as usize;
-
-        let current_random_numeric = seed.wrapping_add(i as u128);
-        let random_part_bytes = current_random_numeric.to_be_bytes();
-
-        salt_bytes[21..32].copy_from_slice(&random_part_bytes[5..]);
-
-        let salt = B256::from(salt_bytes);
-        let guarded_salt = compute::guarded_salt(salt, config.caller, config.chain_id);
-        let computed_address = compute::create3_address(config.deployer, guarded_salt);
+        let salt = cursor.next_salt();
+
+        let guarded_salt = config.scheme.proxy_salt(salt, caller, chain_id);
+        let computed_address = compute::create3_address_with_proxy(
+            config.deployer,
+            guarded_salt,
+            proxy_init_code_hash,
+            proxy.nonce,
+        );
 
         if predicate(computed_address) {
             results.push(Create3Match {
@@ -144,42 +143,157 @@ pub fn mine_create3_salt(
 
 /// Mines for a salt that produces a CREATE3 address with a specific prefix.
 pub fn mine_create3_salt_with_prefix(config: &Create3Config, prefix: &[u8]) -> Create3Result {
-    if prefix.len() > 20 {
-        panic!("Prefix cannot be longer than 20 bytes");
-    }
-    let predicate = |addr: Address| addr.starts_with(prefix);
-    mine_create3_salt(config, &predicate)
+    mine_create3_salt(config, &prefix_predicate(prefix))
 }
 
 /// Mines for a salt that produces a CREATE3 address with a specific suffix.
 pub fn mine_create3_salt_with_suffix(config: &Create3Config, suffix: &[u8]) -> Create3Result {
-    if suffix.len() > 20 {
-        panic!("Suffix cannot be longer than 20 bytes");
-    }
-    let predicate = |addr: Address| addr.ends_with(suffix);
-    mine_create3_salt(config, &predicate)
+    mine_create3_salt(config, &suffix_predicate(suffix))
 }
 
 /// Mines for a salt that produces a CREATE3 address containing a specific byte sequence.
 pub fn mine_create3_salt_with_contains(config: &Create3Config, contains: &[u8]) -> Create3Result {
-    if contains.len() > 20 {
-        panic!("Contained sequence cannot be longer than 20 bytes");
+    mine_create3_salt(config, &contains_predicate(contains))
+}
+
+/// Mines for a salt that produces a CREATE2 address via CreateX's `deployCreate2` satisfying the
+/// given predicate.
+///
+/// The salt is passed through `CreateX#_guard` before CREATE2, so the address matches
+/// `CreateX.computeCreate2Address(guardedSalt, initCodeHash)`.
+///
+/// ## Arguments
+/// * `config` - A `CreateXCreate2Config` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `CreateXCreate2Result` containing the found salts/addresses and total iterations.
+pub fn mine_createx_create2_salt(
+    config: &CreateXCreate2Config,
+    predicate: &dyn Fn(Address) -> bool,
+) -> CreateXCreate2Result {
+    if let Err(e) = config.validate() {
+        panic!("Invalid config: {e}");
     }
-    let predicate = |addr: Address| {
-        addr.windows(contains.len())
-            .any(|window| window == contains)
-    };
-    mine_create3_salt(config, &predicate)
+    let mut cursor = config
+        .effective_salt_template()
+        .cursor(config.seed.unwrap_or(DEFAULT_SEED));
+    cursor.write_reserved(&config.reserved_salt_bytes());
+    let (caller, chain_id) = resolve_guard(config.sender_protection, config.cross_chain_protection)
+        .expect("validated above");
+    let mut results = Vec::new();
+
+    let mut total_iterations = 0;
+
+    for i in 0..config.max_iterations {
+        total_iterations = (i + 1) as usize;
+        let salt = cursor.next_salt();
+
+        let guarded_salt = compute::guarded_salt(salt, caller, chain_id);
+        let computed_address =
+            compute::create2_address(config.deployer, guarded_salt, config.init_code_hash);
+
+        if predicate(computed_address) {
+            results.push(CreateXCreate2Match {
+                salt,
+                guarded_salt,
+                computed_address,
+            });
+
+            if results.len() >= config.max_results as usize {
+                break;
+            }
+        }
+    }
+
+    CreateXCreate2Result {
+        results,
+        total_iterations,
+    }
+}
+
+/// Mines for a salt that produces a CreateX CREATE2 address with a specific prefix.
+pub fn mine_createx_create2_salt_with_prefix(
+    config: &CreateXCreate2Config,
+    prefix: &[u8],
+) -> CreateXCreate2Result {
+    mine_createx_create2_salt(config, &prefix_predicate(prefix))
 }
 
-fn increment_b256_in_place(bytes: &mut B256) {
-    for byte in bytes.iter_mut().rev() {
-        let (result, carry) = byte.overflowing_add(1);
-        *byte = result;
-        if !carry {
-            return;
+/// Mines for a salt that produces a CreateX CREATE2 address with a specific suffix.
+pub fn mine_createx_create2_salt_with_suffix(
+    config: &CreateXCreate2Config,
+    suffix: &[u8],
+) -> CreateXCreate2Result {
+    mine_createx_create2_salt(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a salt that produces a CreateX CREATE2 address containing a specific byte sequence.
+pub fn mine_createx_create2_salt_with_contains(
+    config: &CreateXCreate2Config,
+    contains: &[u8],
+) -> CreateXCreate2Result {
+    mine_createx_create2_salt(config, &contains_predicate(contains))
+}
+
+/// Mines for a nonce at which the sender's CREATE address satisfies the given predicate.
+///
+/// Nonces are scanned upwards from `config.start_nonce`, so the first match is the earliest
+/// nonce the sender can deploy the contract at.
+///
+/// ## Arguments
+/// * `config` - A `CreateConfig` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `CreateResult` containing the found nonces/addresses and total iterations.
+pub fn mine_create_nonce(
+    config: &CreateConfig,
+    predicate: &dyn Fn(Address) -> bool,
+) -> CreateResult {
+    let mut results = Vec::new();
+
+    let mut total_iterations = 0;
+
+    for i in 0..config.max_iterations {
+        let Some(nonce) = config.start_nonce.checked_add(i) else {
+            break;
+        };
+        total_iterations = (i + 1) as usize;
+
+        let computed_address = compute::create_address(config.sender, nonce);
+
+        if predicate(computed_address) {
+            results.push(CreateMatch {
+                nonce,
+                computed_address,
+            });
+
+            if results.len() >= config.max_results as usize {
+                break;
+            }
         }
     }
+
+    CreateResult {
+        results,
+        total_iterations,
+    }
+}
+
+/// Mines for a nonce that produces a CREATE address with a specific prefix.
+pub fn mine_create_nonce_with_prefix(config: &CreateConfig, prefix: &[u8]) -> CreateResult {
+    mine_create_nonce(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a nonce that produces a CREATE address with a specific suffix.
+pub fn mine_create_nonce_with_suffix(config: &CreateConfig, suffix: &[u8]) -> CreateResult {
+    mine_create_nonce(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a nonce that produces a CREATE address containing a specific byte sequence.
+pub fn mine_create_nonce_with_contains(config: &CreateConfig, contains: &[u8]) -> CreateResult {
+    mine_create_nonce(config, &contains_predicate(contains))
 }
 
 // WASM wrapper functions
@@ -191,6 +305,9 @@ pub fn wasm_mine_create3_salt_with_prefix(
 ) -> Result<JsValue, JsValue> {
     let config: Create3Config =
         from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     let mining_result = mine_create3_salt_with_prefix(&config, prefix);
     to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
@@ -204,6 +321,9 @@ pub fn wasm_mine_create3_salt_with_suffix(
 ) -> Result<JsValue, JsValue> {
     let config: Create3Config =
         from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     let mining_result = mine_create3_salt_with_suffix(&config, suffix);
     to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
@@ -217,6 +337,9 @@ pub fn wasm_mine_create3_salt_with_contains(
 ) -> Result<JsValue, JsValue> {
     let config: Create3Config =
         from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     let mining_result = mine_create3_salt_with_contains(&config, contains);
     to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
@@ -230,6 +353,9 @@ pub fn wasm_mine_create2_salt_with_prefix(
 ) -> Result<JsValue, JsValue> {
     let config: Create2Config =
         from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     let mining_result = mine_create2_salt_with_prefix(&config, prefix);
     to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
@@ -243,6 +369,9 @@ pub fn wasm_mine_create2_salt_with_suffix(
 ) -> Result<JsValue, JsValue> {
     let config: Create2Config =
         from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     let mining_result = mine_create2_salt_with_suffix(&config, suffix);
     to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
@@ -256,32 +385,174 @@ pub fn wasm_mine_create2_salt_with_contains(
 ) -> Result<JsValue, JsValue> {
     let config: Create2Config =
         from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     let mining_result = mine_create2_salt_with_contains(&config, contains);
     to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
 }
 
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_createx_create2_salt_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CreateXCreate2Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_createx_create2_salt_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_createx_create2_salt_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CreateXCreate2Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_createx_create2_salt_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_createx_create2_salt_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CreateXCreate2Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_createx_create2_salt_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_create_nonce_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CreateConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_create_nonce_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_create_nonce_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CreateConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_create_nonce_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_create_nonce_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: CreateConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_create_nonce_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::createx::compute;
-    use crate::createx::config::Create3Config;
-    use alloy_primitives::address;
+    use crate::createx::config::{
+        Create3Config, Create3Proxy, Create3Scheme, CreateConfig, CreateXCreate2Config,
+        CrossChainProtection, SenderProtection,
+    };
+    use crate::createx::template::{SaltRange, SaltTemplate};
+    use alloy_primitives::{B256, address, b256, bytes};
 
     const DEPLOYER: Address = address!("ba5ed099633d3b313e4d5f7bdc1305d3c28ba5ed");
     const CALLER: Address = address!("DeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF");
 
+    #[test]
+    fn test_mine_create3_default_layout_is_stable() {
+        // Salts and addresses produced by the miner before salt templates were introduced.
+        let config = Create3Config {
+            deployer: DEPLOYER,
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::ZeroAddress,
+            cross_chain_protection: CrossChainProtection::Disabled,
+            max_iterations: 2,
+            max_results: 2,
+            seed: Some(1337),
+            salt_template: None,
+        };
+        let results = mine_create3_salt(&config, &|_| true).results;
+        assert_eq!(
+            results[0].salt,
+            b256!("0000000000000000000000000000000000000000000000000000000000000539")
+        );
+        assert_eq!(
+            results[0].computed_address,
+            address!("f6377f8c6c3faf35e7cb63104a83446345c80e4e")
+        );
+        assert_eq!(
+            results[1].computed_address,
+            address!("146d2a2458e30b96bea4bd82f65e7ee8e8594dfe")
+        );
+
+        let config = Create3Config {
+            sender_protection: SenderProtection::Caller(address!(
+                "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+            )),
+            cross_chain_protection: CrossChainProtection::Enabled(130),
+            ..config
+        };
+        let results = mine_create3_salt(&config, &|_| true).results;
+        assert_eq!(
+            results[0].salt,
+            b256!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48010000000000000000000539")
+        );
+        assert_eq!(
+            results[0].guarded_salt,
+            b256!("8dd9c840b4a24981a8430b44e541b4cadc568eac6a6e2894144bdfaa909be6a7")
+        );
+        assert_eq!(
+            results[0].computed_address,
+            address!("05d642ea3dfa86a9b6a8acedb2e46c85d7d78314")
+        );
+    }
+
     #[test]
     fn test_mine_create3_for_leading_zeros() {
         let chain_id = 130u64;
 
         let config = Create3Config {
             deployer: DEPLOYER,
-            caller: Some(CALLER),
-            chain_id: Some(chain_id),
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::Caller(CALLER),
+            cross_chain_protection: CrossChainProtection::Enabled(chain_id),
             max_iterations: 1_000_000,
             max_results: 1,
             seed: Some(1234),
+            salt_template: None,
         };
 
         let mining_result = mine_create3_salt(&config, &|addr| addr[0] == 0x00);
@@ -325,11 +596,13 @@ mod tests {
     fn test_mine_create3_for_specific_suffix() {
         let config = Create3Config {
             deployer: DEPLOYER,
-            caller: None,
-            chain_id: None,
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::ZeroAddress,
+            cross_chain_protection: CrossChainProtection::Disabled,
             max_iterations: 1_000_000,
             max_results: 1,
             seed: Some(1234),
+            salt_template: None,
         };
 
         let suffix = &[0xba, 0xbe];
@@ -363,11 +636,13 @@ mod tests {
 
         let config = Create3Config {
             deployer: DEPLOYER,
-            caller: Some(CALLER),
-            chain_id: Some(chain_id),
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::Caller(CALLER),
+            cross_chain_protection: CrossChainProtection::Enabled(chain_id),
             max_iterations: 1_000_000,
             max_results: 1,
             seed: Some(1234),
+            salt_template: None,
         };
 
         let prefix = &[0x27, 0x18];
@@ -412,11 +687,13 @@ mod tests {
     fn test_mine_create3_for_contains() {
         let config = Create3Config {
             deployer: DEPLOYER,
-            caller: None,
-            chain_id: None,
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::ZeroAddress,
+            cross_chain_protection: CrossChainProtection::Disabled,
             max_iterations: 1_000_000,
             max_results: 1,
             seed: Some(1234),
+            salt_template: None,
         };
 
         let contains = &[0xab, 0xcd];
@@ -451,11 +728,13 @@ mod tests {
     fn test_mine_create3_multiple_results() {
         let config = Create3Config {
             deployer: DEPLOYER,
-            caller: None,
-            chain_id: None,
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::ZeroAddress,
+            cross_chain_protection: CrossChainProtection::Disabled,
             max_iterations: 10_000_000,
             max_results: 3,
             seed: Some(1234),
+            salt_template: None,
         };
 
         let mining_result = mine_create3_salt(&config, &|addr| addr[0] < 0x10);
@@ -483,4 +762,285 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_mine_create3_with_salt_template() {
+        let mut fixed = B256::ZERO;
+        fixed[21..25].copy_from_slice(&[0xca, 0xfe, 0xba, 0xbe]);
+        let template = SaltTemplate {
+            fixed,
+            mined: vec![SaltRange::new(25, 32)],
+            counter: SaltRange::new(28, 32),
+        };
+
+        let config = Create3Config {
+            deployer: DEPLOYER,
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::Caller(CALLER),
+            cross_chain_protection: CrossChainProtection::Disabled,
+            max_iterations: 1_000_000,
+            max_results: 2,
+            seed: Some(1234),
+            salt_template: Some(template),
+        };
+
+        let mining_result = mine_create3_salt(&config, &|addr| addr[0] == 0x00);
+        assert_eq!(
+            mining_result.results.len(),
+            2,
+            "Failed to find two addresses with leading zero byte within {} iterations",
+            config.max_iterations
+        );
+
+        let (first, second) = (&mining_result.results[0], &mining_result.results[1]);
+        for result in [first, second] {
+            assert_eq!(
+                &result.salt[0..20],
+                CALLER.as_slice(),
+                "Salt should contain caller address in bytes 0-19"
+            );
+            assert_eq!(
+                &result.salt[21..25],
+                &[0xca, 0xfe, 0xba, 0xbe],
+                "Fixed template bytes should be preserved"
+            );
+            assert_eq!(
+                result.computed_address,
+                compute::create3_address(DEPLOYER, result.guarded_salt),
+                "Address computation mismatch"
+            );
+        }
+        assert_eq!(
+            &first.salt[..28],
+            &second.salt[..28],
+            "Only the counter bytes should differ between results"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "reserved by the deployment scheme")]
+    fn test_mine_create3_rejects_template_over_reserved_bytes() {
+        let config = Create3Config {
+            deployer: DEPLOYER,
+            scheme: Create3Scheme::CreateX,
+            sender_protection: SenderProtection::ZeroAddress,
+            cross_chain_protection: CrossChainProtection::Disabled,
+            max_iterations: 1,
+            max_results: 1,
+            seed: None,
+            salt_template: Some(SaltTemplate::fully_mined()),
+        };
+
+        mine_create3_salt(&config, &|_| true);
+    }
+
+    #[test]
+    fn test_mine_createx_create2_with_guarded_salt() {
+        let chain_id = 130u64;
+        let init_code_hash = keccak256([0x60, 0x00, 0x60, 0x00, 0xf3]);
+
+        let config = CreateXCreate2Config {
+            deployer: DEPLOYER,
+            init_code_hash,
+            sender_protection: SenderProtection::Caller(CALLER),
+            cross_chain_protection: CrossChainProtection::Enabled(chain_id),
+            max_iterations: 1_000_000,
+            max_results: 1,
+            salt_template: None,
+            seed: Some(1234),
+        };
+
+        let prefix = &[0x27, 0x18];
+        let mining_result = mine_createx_create2_salt_with_prefix(&config, prefix);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find address starting with 0x2718 within {} iterations",
+            config.max_iterations
+        );
+
+        let result = &mining_result.results[0];
+
+        assert!(
+            result.computed_address.starts_with(prefix),
+            "Address should start with prefix 0x2718"
+        );
+        assert_eq!(
+            &result.salt[0..20],
+            CALLER.as_slice(),
+            "Salt should contain caller address for permission protection"
+        );
+        assert_eq!(
+            result.salt[20], 0x01,
+            "Salt byte 20 should be 0x01 for cross-chain protection"
+        );
+
+        let expected_guarded_salt =
+            compute::guarded_salt(result.salt, Some(CALLER), Some(chain_id));
+        assert_eq!(
+            result.guarded_salt, expected_guarded_salt,
+            "Guarded salt should match expected computation"
+        );
+
+        let expected_address =
+            compute::create2_address(DEPLOYER, result.guarded_salt, init_code_hash);
+        assert_eq!(
+            result.computed_address, expected_address,
+            "Address should be the CREATE2 address of the guarded salt"
+        );
+    }
+
+    #[test]
+    fn test_createx_create2_guarded_salt_vector() {
+        // Permissioned, cross-chain protected salt for CALLER on chain 130, as passed to
+        // `CreateX.deployCreate2`. The guarded salt is `keccak256(abi.encode(CALLER, 130, salt))`
+        // and the address is what `computeCreate2Address(guardedSalt, initCodeHash)` returns.
+        let salt = b256!("deadbeefdeadbeefdeadbeefdeadbeefdeadbeef01000000000000000000002a");
+        let init_code_hash = keccak256([0x60, 0x00, 0x60, 0x00, 0xf3]);
+
+        let guarded_salt = compute::guarded_salt(salt, Some(CALLER), Some(130));
+        assert_eq!(
+            guarded_salt,
+            b256!("fef37fb28ba13a1bc6798f1fff467b64691a7de5487d07bddada18ed61920cc9"),
+            "Guarded salt mismatch"
+        );
+        assert_eq!(
+            compute::create2_address(DEPLOYER, guarded_salt, init_code_hash),
+            address!("3985a5515073ce4df8ffdd035eb00fa13cf80f15"),
+            "CreateX CREATE2 address mismatch"
+        );
+    }
+
+    #[test]
+    fn test_mine_create_nonce_for_prefix() {
+        let config = CreateConfig {
+            sender: CALLER,
+            start_nonce: 5,
+            max_iterations: 100_000,
+            max_results: 2,
+        };
+
+        let prefix = &[0xc0];
+        let mining_result = mine_create_nonce_with_prefix(&config, prefix);
+        assert_eq!(
+            mining_result.results.len(),
+            2,
+            "Failed to find two nonces for prefix 0xc0 within {} iterations",
+            config.max_iterations
+        );
+
+        let (first, second) = (&mining_result.results[0], &mining_result.results[1]);
+        assert!(
+            first.nonce >= 5,
+            "Nonces below the start nonce must be skipped"
+        );
+        assert!(
+            first.nonce < second.nonce,
+            "Nonces should be scanned upwards"
+        );
+        for result in [first, second] {
+            assert!(
+                result.computed_address.starts_with(prefix),
+                "Address should start with prefix 0xc0"
+            );
+            assert_eq!(
+                result.computed_address,
+                compute::create_address(CALLER, result.nonce),
+                "Address computation mismatch"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mine_create_nonce_stops_at_max_nonce() {
+        let config = CreateConfig {
+            sender: CALLER,
+            start_nonce: u64::MAX - 1,
+            max_iterations: 10,
+            max_results: 10,
+        };
+
+        let mining_result = mine_create_nonce(&config, &|_| true);
+        assert_eq!(
+            mining_result.total_iterations, 2,
+            "Mining should stop once the nonce space is exhausted"
+        );
+    }
+
+    #[test]
+    fn test_mine_create3_for_other_schemes() {
+        let proxy = Create3Proxy {
+            init_code: bytes!("67363d3d37363d34f03d5260086018f3"),
+            nonce: 2,
+        };
+        let schemes = [
+            Create3Scheme::Solady,
+            Create3Scheme::Sequence,
+            Create3Scheme::ZeframLou { caller: CALLER },
+            Create3Scheme::Custom(proxy.clone()),
+        ];
+
+        for scheme in schemes {
+            let config = Create3Config {
+                deployer: DEPLOYER,
+                scheme: scheme.clone(),
+                sender_protection: SenderProtection::ZeroAddress,
+                cross_chain_protection: CrossChainProtection::Disabled,
+                max_iterations: 1_000_000,
+                max_results: 1,
+                salt_template: None,
+                seed: Some(1234),
+            };
+
+            let mining_result = mine_create3_salt(&config, &|addr| addr[0] == 0x00);
+            assert!(
+                !mining_result.results.is_empty(),
+                "Failed to find address with leading zero byte for {:?}",
+                scheme
+            );
+
+            let result = &mining_result.results[0];
+            let (expected_salt, expected_address) = match &scheme {
+                Create3Scheme::ZeframLou { caller } => {
+                    let salt = compute::zeframlou_salt(*caller, result.salt);
+                    (salt, compute::create3_address(DEPLOYER, salt))
+                }
+                Create3Scheme::Custom(proxy) => {
+                    let proxy_address = compute::create2_address(
+                        DEPLOYER,
+                        result.salt,
+                        keccak256(&proxy.init_code),
+                    );
+                    (result.salt, compute::create_address(proxy_address, 2))
+                }
+                _ => (result.salt, compute::create3_address(DEPLOYER, result.salt)),
+            };
+            assert_eq!(
+                result.guarded_salt, expected_salt,
+                "Proxy salt mismatch for {:?}",
+                scheme
+            );
+            assert_eq!(
+                result.computed_address, expected_address,
+                "Address computation mismatch for {:?}",
+                scheme
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "only interpreted by CreateX")]
+    fn test_mine_create3_rejects_protection_for_other_schemes() {
+        let config = Create3Config {
+            deployer: DEPLOYER,
+            scheme: Create3Scheme::Solady,
+            sender_protection: SenderProtection::Caller(CALLER),
+            cross_chain_protection: CrossChainProtection::Disabled,
+            max_iterations: 1,
+            max_results: 1,
+            salt_template: None,
+            seed: None,
+        };
+
+        mine_create3_salt(&config, &|_| true);
+    }
 }
@@ -1,13 +1,28 @@
+pub mod calldata;
 pub mod compute;
 pub mod config;
 pub mod miner;
+pub mod presets;
+pub mod template;
 
-pub use compute::{create2_address, create3_address, guarded_salt};
+pub use calldata::{CalldataError, CreateXInit, deploy_create2_calldata, deploy_create3_calldata};
+pub use compute::{
+    create_address, create2_address, create3_address, create3_address_with_proxy, guarded_salt,
+    zeframlou_salt,
+};
 pub use config::{
-    Create2Config, Create2Match, Create2Result, Create3Config, Create3Match, Create3Result,
+    ConfigError, Create2Config, Create2Match, Create2Result, Create3Config, Create3Match,
+    Create3Proxy, Create3Result, Create3Scheme, CreateConfig, CreateMatch, CreateResult,
+    CreateXCreate2Config, CreateXCreate2Match, CreateXCreate2Result, CrossChainProtection,
+    SenderProtection, createx_salt_prefix, resolve_guard,
 };
 pub use miner::{
-    mine_create2_salt, mine_create2_salt_with_contains, mine_create2_salt_with_prefix,
-    mine_create2_salt_with_suffix, mine_create3_salt, mine_create3_salt_with_contains,
-    mine_create3_salt_with_prefix, mine_create3_salt_with_suffix,
+    mine_create_nonce, mine_create_nonce_with_contains, mine_create_nonce_with_prefix,
+    mine_create_nonce_with_suffix, mine_create2_salt, mine_create2_salt_with_contains,
+    mine_create2_salt_with_prefix, mine_create2_salt_with_suffix, mine_create3_salt,
+    mine_create3_salt_with_contains, mine_create3_salt_with_prefix, mine_create3_salt_with_suffix,
+    mine_createx_create2_salt, mine_createx_create2_salt_with_contains,
+    mine_createx_create2_salt_with_prefix, mine_createx_create2_salt_with_suffix,
 };
+pub use presets::{CalldataLayout, Create2Preset, Create2PresetInfo};
+pub use template::{SaltRange, SaltTemplate, SaltTemplateError};
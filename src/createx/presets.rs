@@ -0,0 +1,259 @@
+use crate::createx::config::{ConfigError, Create2Config};
+use crate::createx::template::SaltTemplate;
+use alloy_primitives::{Address, B256, Bytes, address};
+use alloy_sol_types::{SolCall, sol};
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+use std::str::FromStr;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+sol! {
+    /// See [`ImmutableCreate2Factory`](https://etherscan.io/address/0x0000000000ffe8b47b3e2130213b802212439497#code).
+    interface IImmutableCreate2Factory {
+        function safeCreate2(bytes32 salt, bytes calldata initializationCode) external payable returns (address deploymentAddress);
+    }
+}
+
+/// How a deployer expects the salt and init code in its calldata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalldataLayout {
+    /// `salt ‖ init_code`, sent to the fallback function of the deployer.
+    SaltAndInitCode,
+    /// `safeCreate2(bytes32 salt, bytes initializationCode)`.
+    SafeCreate2,
+}
+
+/// Well-known CREATE2 deployers that are available at the same address on most chains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Create2Preset {
+    /// Arachnid's deterministic deployment proxy.
+    ///
+    /// See [`deterministic-deployment-proxy`](https://github.com/Arachnid/deterministic-deployment-proxy).
+    DeterministicDeploymentProxy,
+    /// The Safe singleton factory.
+    ///
+    /// See [`safe-singleton-factory`](https://github.com/safe-global/safe-singleton-factory).
+    SafeSingletonFactory,
+    /// 0age's `ImmutableCreate2Factory`, which requires the salt to start with the caller or
+    /// the zero address.
+    ImmutableCreate2Factory,
+}
+
+impl Create2Preset {
+    /// All known presets.
+    pub const ALL: [Self; 3] = [
+        Self::DeterministicDeploymentProxy,
+        Self::SafeSingletonFactory,
+        Self::ImmutableCreate2Factory,
+    ];
+
+    /// The name of the preset, as accepted by `from_str`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::DeterministicDeploymentProxy => "deterministic_deployment_proxy",
+            Self::SafeSingletonFactory => "safe_singleton_factory",
+            Self::ImmutableCreate2Factory => "immutable_create2_factory",
+        }
+    }
+
+    /// The address of the deployer.
+    pub const fn address(&self) -> Address {
+        match self {
+            Self::DeterministicDeploymentProxy => {
+                address!("4e59b44847b379578588920ca78fbf26c0b4956c")
+            }
+            Self::SafeSingletonFactory => address!("914d7fec6aac8cd542e72bca78b30650d45643d7"),
+            Self::ImmutableCreate2Factory => address!("0000000000ffe8b47b3e2130213b802212439497"),
+        }
+    }
+
+    /// The calldata layout the deployer expects.
+    pub const fn calldata_layout(&self) -> CalldataLayout {
+        match self {
+            Self::DeterministicDeploymentProxy | Self::SafeSingletonFactory => {
+                CalldataLayout::SaltAndInitCode
+            }
+            Self::ImmutableCreate2Factory => CalldataLayout::SafeCreate2,
+        }
+    }
+
+    /// The salt template the deployer requires, if any.
+    ///
+    /// `ImmutableCreate2Factory` keeps the leading 20 bytes zero so anyone may submit the
+    /// deployment. Put the caller address there instead to prevent front-running.
+    pub fn salt_template(&self) -> Option<SaltTemplate> {
+        match self {
+            Self::ImmutableCreate2Factory => Some(SaltTemplate::mined_after(20)),
+            _ => None,
+        }
+    }
+
+    /// Encodes the calldata deploying `init_code` with `salt` through the deployer.
+    pub fn calldata(&self, salt: B256, init_code: &[u8]) -> Bytes {
+        match self.calldata_layout() {
+            CalldataLayout::SaltAndInitCode => {
+                let mut calldata = Vec::with_capacity(32 + init_code.len());
+                calldata.extend_from_slice(salt.as_slice());
+                calldata.extend_from_slice(init_code);
+                calldata.into()
+            }
+            CalldataLayout::SafeCreate2 => IImmutableCreate2Factory::safeCreate2Call {
+                salt,
+                initializationCode: Bytes::copy_from_slice(init_code),
+            }
+            .abi_encode()
+            .into(),
+        }
+    }
+}
+
+impl FromStr for Create2Preset {
+    type Err = ConfigError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name() == name)
+            .ok_or_else(|| ConfigError::UnknownPreset(name.to_string()))
+    }
+}
+
+impl Create2Config {
+    /// Creates a CREATE2 mining configuration for a well-known deployer.
+    pub fn from_preset(
+        preset: Create2Preset,
+        init_code_hash: B256,
+        max_iterations: u64,
+        max_results: u64,
+        seed: Option<u128>,
+    ) -> Self {
+        Self {
+            deployer: preset.address(),
+            init_code_hash,
+            max_iterations,
+            max_results,
+            salt_template: preset.salt_template(),
+            seed,
+        }
+    }
+}
+
+/// A preset as exposed to WASM, ready to be merged into a `Create2Config`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Create2PresetInfo {
+    pub name: String,
+    pub deployer: Address,
+    pub calldata_layout: CalldataLayout,
+    pub salt_template: Option<SaltTemplate>,
+}
+
+impl From<Create2Preset> for Create2PresetInfo {
+    fn from(preset: Create2Preset) -> Self {
+        Self {
+            name: preset.name().to_string(),
+            deployer: preset.address(),
+            calldata_layout: preset.calldata_layout(),
+            salt_template: preset.salt_template(),
+        }
+    }
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_create2_preset(name: &str) -> Result<JsValue, JsValue> {
+    let preset: Create2Preset = name
+        .parse()
+        .map_err(|e: ConfigError| JsValue::from_str(&e.to_string()))?;
+
+    to_value(&Create2PresetInfo::from(preset)).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_create2_preset_calldata(
+    name: &str,
+    salt: JsValue,
+    init_code: &[u8],
+) -> Result<JsValue, JsValue> {
+    let preset: Create2Preset = name
+        .parse()
+        .map_err(|e: ConfigError| JsValue::from_str(&e.to_string()))?;
+    let salt: B256 = from_value(salt).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let calldata = preset.calldata(salt, init_code);
+    to_value(&calldata).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::createx::compute::create2_address;
+    use crate::createx::miner::mine_create2_salt;
+    use alloy_primitives::{b256, bytes, keccak256};
+
+    #[test]
+    fn test_preset_names_round_trip() {
+        for preset in Create2Preset::ALL {
+            assert_eq!(preset.name().parse::<Create2Preset>(), Ok(preset));
+        }
+        assert_eq!(
+            "create2".parse::<Create2Preset>(),
+            Err(ConfigError::UnknownPreset("create2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_preset_calldata() {
+        let salt = b256!("00000000000000000000000000000000000000000000000000000000000000ff");
+        let init_code = bytes!("6080604052348015600f57600080fd5b50");
+
+        let calldata = Create2Preset::DeterministicDeploymentProxy.calldata(salt, &init_code);
+        assert_eq!(&calldata[..32], salt.as_slice(), "Salt must come first");
+        assert_eq!(
+            &calldata[32..],
+            &init_code[..],
+            "Init code must follow the salt"
+        );
+
+        let calldata = Create2Preset::ImmutableCreate2Factory.calldata(salt, &init_code);
+        assert_eq!(
+            calldata[..4],
+            keccak256("safeCreate2(bytes32,bytes)")[..4],
+            "Selector mismatch for safeCreate2"
+        );
+        let decoded = IImmutableCreate2Factory::safeCreate2Call::abi_decode(&calldata).unwrap();
+        assert_eq!(decoded.salt, salt);
+        assert_eq!(decoded.initializationCode, init_code);
+    }
+
+    #[test]
+    fn test_mine_with_immutable_create2_factory_preset() {
+        let init_code_hash = keccak256([0x60, 0x00, 0x60, 0x00, 0xf3]);
+        let preset: Create2Preset = "immutable_create2_factory".parse().unwrap();
+        let config = Create2Config::from_preset(preset, init_code_hash, 100_000, 1, Some(1234));
+
+        let mining_result = mine_create2_salt(&config, &|addr| addr[0] == 0x00);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find address with leading zero byte within {} iterations",
+            config.max_iterations
+        );
+
+        let result = &mining_result.results[0];
+        assert_eq!(
+            &result.salt[0..20],
+            Address::ZERO.as_slice(),
+            "ImmutableCreate2Factory salts must start with the zero address or caller"
+        );
+        assert_eq!(
+            result.computed_address,
+            create2_address(preset.address(), result.salt, init_code_hash),
+            "Address computation mismatch"
+        );
+    }
+}
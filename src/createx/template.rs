@@ -0,0 +1,325 @@
+use alloy_primitives::{B256, keccak256};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A half-open byte range `start..end` within a 32-byte salt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaltRange {
+    pub start: u8,
+    pub end: u8,
+}
+
+impl SaltRange {
+    pub const fn new(start: u8, end: u8) -> Self {
+        Self { start, end }
+    }
+
+    /// The number of bytes covered by the range.
+    pub const fn len(&self) -> usize {
+        self.end.saturating_sub(self.start) as usize
+    }
+
+    /// Whether the range covers no bytes.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn is_valid(&self) -> bool {
+        self.start < self.end && self.end <= 32
+    }
+
+    fn overlaps(&self, other: &SaltRange) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    fn covers(&self, other: &SaltRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+}
+
+/// Errors raised when a `SaltTemplate` is incompatible with itself or the deployment scheme.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SaltTemplateError {
+    #[error("salt range {start}..{end} is empty or exceeds 32 bytes")]
+    InvalidRange { start: u8, end: u8 },
+    #[error("mined salt ranges {0:?} and {1:?} overlap")]
+    OverlappingRanges(SaltRange, SaltRange),
+    #[error("counter range {0:?} is not contained in a single mined range")]
+    CounterNotMined(SaltRange),
+    #[error(
+        "salt bytes 0..{reserved} are reserved by the deployment scheme, but {range:?} is mined"
+    )]
+    ReservedRange { reserved: usize, range: SaltRange },
+    #[error("fixed salt bytes 0..{reserved} conflict with the deployment scheme")]
+    ReservedBytesMismatch { reserved: usize },
+    #[error("a {len}-byte counter cannot hold {max_iterations} iterations")]
+    CounterTooSmall { len: usize, max_iterations: u64 },
+}
+
+/// Describes the layout of a salt: which bytes are fixed, which are mined, and where the
+/// iteration counter lives.
+///
+/// Mined bytes are filled from `keccak256(seed)` so different seeds explore different salts, and
+/// the counter is incremented as a big-endian integer on every iteration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaltTemplate {
+    /// The salt bytes used verbatim outside of the mined ranges.
+    pub fixed: B256,
+    /// The byte ranges the miner is allowed to overwrite.
+    pub mined: Vec<SaltRange>,
+    /// The byte range holding the iteration counter. Must lie within a mined range.
+    pub counter: SaltRange,
+}
+
+impl SaltTemplate {
+    /// A template where every byte of the salt is mined.
+    pub fn fully_mined() -> Self {
+        Self::mined_after(0)
+    }
+
+    /// A template where bytes `offset..32` are mined and the rest are zero.
+    pub fn mined_after(offset: u8) -> Self {
+        let range = SaltRange::new(offset, 32);
+        Self {
+            fixed: B256::ZERO,
+            mined: vec![range],
+            counter: range,
+        }
+    }
+
+    /// Validates the template against a deployment scheme.
+    ///
+    /// ## Arguments
+    /// * `reserved` - The leading salt bytes the scheme writes itself, with their expected values.
+    /// * `max_iterations` - The iteration budget the counter has to accommodate.
+    pub fn validate(&self, reserved: &[u8], max_iterations: u64) -> Result<(), SaltTemplateError> {
+        for (i, range) in self.mined.iter().enumerate() {
+            if !range.is_valid() {
+                return Err(SaltTemplateError::InvalidRange {
+                    start: range.start,
+                    end: range.end,
+                });
+            }
+            if (range.start as usize) < reserved.len() {
+                return Err(SaltTemplateError::ReservedRange {
+                    reserved: reserved.len(),
+                    range: *range,
+                });
+            }
+            if let Some(other) = self.mined[..i].iter().find(|other| other.overlaps(range)) {
+                return Err(SaltTemplateError::OverlappingRanges(*other, *range));
+            }
+        }
+
+        if !self.counter.is_valid() {
+            return Err(SaltTemplateError::InvalidRange {
+                start: self.counter.start,
+                end: self.counter.end,
+            });
+        }
+        if !self.mined.iter().any(|range| range.covers(&self.counter)) {
+            return Err(SaltTemplateError::CounterNotMined(self.counter));
+        }
+        let len = self.counter.len();
+        if len < 8 && max_iterations > 1u64 << (len * 8) {
+            return Err(SaltTemplateError::CounterTooSmall {
+                len,
+                max_iterations,
+            });
+        }
+
+        let fixed = &self.fixed[..reserved.len()];
+        if fixed.iter().any(|b| *b != 0) && fixed != reserved {
+            return Err(SaltTemplateError::ReservedBytesMismatch {
+                reserved: reserved.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Creates a cursor over the salts described by this template.
+    ///
+    /// The template must have been validated beforehand.
+    pub(crate) fn cursor(&self, seed: u128) -> SaltCursor {
+        let random = keccak256(seed.to_be_bytes());
+        let mut salt = self.fixed.0;
+        for range in &self.mined {
+            let bytes = range.start as usize..range.end as usize;
+            salt[bytes.clone()].copy_from_slice(&random[bytes]);
+        }
+
+        SaltCursor {
+            salt,
+            counter_start: self.counter.start as usize,
+            counter_end: self.counter.end as usize,
+        }
+    }
+
+    /// Creates a cursor whose counter starts at `seed` instead of a hash of it, leaving the other
+    /// mined bytes zero.
+    ///
+    /// This is the layout CREATE3 mining used before templates existed, so saved seeds keep
+    /// producing the same salts.
+    pub(crate) fn sequential_cursor(&self, seed: u128) -> SaltCursor {
+        let mut salt = self.fixed.0;
+        for range in &self.mined {
+            salt[range.start as usize..range.end as usize].fill(0);
+        }
+        let counter = &mut salt[self.counter.start as usize..self.counter.end as usize];
+        let seed = seed.to_be_bytes();
+        let len = counter.len().min(seed.len());
+        let counter_len = counter.len();
+        counter[counter_len - len..].copy_from_slice(&seed[seed.len() - len..]);
+
+        let mut cursor = SaltCursor {
+            salt,
+            counter_start: self.counter.start as usize,
+            counter_end: self.counter.end as usize,
+        };
+        // `next_salt` increments before returning, so the first salt holds `seed` itself.
+        cursor.step_back();
+        cursor
+    }
+}
+
+/// Walks through the salts of a `SaltTemplate` by incrementing its counter bytes.
+pub(crate) struct SaltCursor {
+    salt: [u8; 32],
+    counter_start: usize,
+    counter_end: usize,
+}
+
+impl SaltCursor {
+    /// Overwrites the leading salt bytes reserved by the deployment scheme.
+    pub(crate) fn write_reserved(&mut self, reserved: &[u8]) {
+        self.salt[..reserved.len()].copy_from_slice(reserved);
+    }
+
+    /// Decrements the counter, wrapping around within the counter bytes.
+    fn step_back(&mut self) {
+        for byte in self.salt[self.counter_start..self.counter_end]
+            .iter_mut()
+            .rev()
+        {
+            let (result, borrow) = byte.overflowing_sub(1);
+            *byte = result;
+            if !borrow {
+                break;
+            }
+        }
+    }
+
+    /// Increments the counter and returns the resulting salt.
+    pub(crate) fn next_salt(&mut self) -> B256 {
+        for byte in self.salt[self.counter_start..self.counter_end]
+            .iter_mut()
+            .rev()
+        {
+            let (result, carry) = byte.overflowing_add(1);
+            *byte = result;
+            if !carry {
+                break;
+            }
+        }
+        B256::from(self.salt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn test_cursor_keeps_fixed_bytes_and_counts() {
+        let template = SaltTemplate {
+            fixed: b256!("c0ffee0000000000000000000000000000000000000000000000000000000000"),
+            mined: vec![SaltRange::new(8, 16), SaltRange::new(28, 32)],
+            counter: SaltRange::new(28, 32),
+        };
+        template.validate(&[], 1_000).unwrap();
+
+        let mut cursor = template.cursor(42);
+        let first = cursor.next_salt();
+        let second = cursor.next_salt();
+
+        assert_eq!(
+            &first[0..8],
+            &template.fixed[0..8],
+            "Fixed bytes must be kept"
+        );
+        assert_eq!(&first[16..28], &[0u8; 12], "Unmined bytes must be kept");
+        assert_eq!(&first[..28], &second[..28], "Only the counter may change");
+        assert_eq!(
+            u32::from_be_bytes(second[28..32].try_into().unwrap()),
+            u32::from_be_bytes(first[28..32].try_into().unwrap()).wrapping_add(1),
+            "Counter should increment by one per salt"
+        );
+    }
+
+    #[test]
+    fn test_sequential_cursor_starts_at_seed() {
+        let mut cursor = SaltTemplate::mined_after(21).sequential_cursor(1337);
+        assert_eq!(
+            cursor.next_salt(),
+            b256!("0000000000000000000000000000000000000000000000000000000000000539")
+        );
+        assert_eq!(
+            cursor.next_salt(),
+            b256!("000000000000000000000000000000000000000000000000000000000000053a")
+        );
+
+        let mut cursor = SaltTemplate::fully_mined().sequential_cursor(0);
+        assert_eq!(
+            cursor.next_salt(),
+            B256::ZERO,
+            "A zero seed must not wrap into the upper salt bytes"
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_templates() {
+        let mut template = SaltTemplate::mined_after(16);
+        template.mined.push(SaltRange::new(10, 20));
+        assert_eq!(
+            template.validate(&[], 1),
+            Err(SaltTemplateError::OverlappingRanges(
+                SaltRange::new(16, 32),
+                SaltRange::new(10, 20)
+            ))
+        );
+
+        let mut template = SaltTemplate::mined_after(16);
+        template.counter = SaltRange::new(8, 12);
+        assert_eq!(
+            template.validate(&[], 1),
+            Err(SaltTemplateError::CounterNotMined(SaltRange::new(8, 12)))
+        );
+
+        let mut template = SaltTemplate::mined_after(16);
+        template.counter = SaltRange::new(31, 32);
+        assert_eq!(
+            template.validate(&[], 1_000),
+            Err(SaltTemplateError::CounterTooSmall {
+                len: 1,
+                max_iterations: 1_000
+            })
+        );
+
+        assert_eq!(
+            SaltTemplate::mined_after(16).validate(&[0u8; 21], 1),
+            Err(SaltTemplateError::ReservedRange {
+                reserved: 21,
+                range: SaltRange::new(16, 32)
+            })
+        );
+
+        let mut template = SaltTemplate::mined_after(21);
+        template.fixed.0[20] = 0x01;
+        assert_eq!(
+            template.validate(&[0u8; 21], 1),
+            Err(SaltTemplateError::ReservedBytesMismatch { reserved: 21 })
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use alloy_primitives::{Address, B256, Bytes, keccak256};
+use alloy_sol_types::{SolCall, SolValue, sol};
+
+sol! {
+    /// See [`SimpleAccount`](https://github.com/eth-infinitism/account-abstraction/blob/v0.7.0/contracts/samples/SimpleAccount.sol).
+    interface ISimpleAccount {
+        function initialize(address anOwner) external;
+    }
+}
+
+const ERC1967_CREATION_HEAD: &[u8] = &[0x60, 0x3d, 0x3d, 0x81, 0x60, 0x22, 0x3d, 0x39, 0x73];
+const ERC1967_CREATION_TAIL: &[u8] = &[0x60, 0x09, 0x51, 0x55, 0xf3];
+const ERC1967_RUNTIME: &[u8] = &[
+    0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d, 0x36, 0x3d, 0x7f, 0x36, 0x08, 0x94, 0xa1, 0x3b, 0xa1, 0xa3,
+    0x21, 0x06, 0x67, 0xc8, 0x28, 0x49, 0x2d, 0xb9, 0x8d, 0xca, 0x3e, 0x20, 0x76, 0xcc, 0x37, 0x35,
+    0xa9, 0x20, 0xa3, 0xca, 0x50, 0x5d, 0x38, 0x2b, 0xbc, 0x54, 0x5a, 0xf4, 0x3d, 0x60, 0x00, 0x80,
+    0x3e, 0x60, 0x38, 0x57, 0x3d, 0x60, 0x00, 0xfd, 0x5b, 0x3d, 0x60, 0x00, 0xf3,
+];
+
+/// Computes the creation code of Solady's minimal ERC1967 proxy, used by Kernel and Light Account.
+///
+/// See [`LibClone#initCodeERC1967`](https://github.com/Vectorized/solady/blob/main/src/utils/LibClone.sol).
+pub fn creation_code_erc1967_proxy(implementation: Address) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(95);
+    bytes.extend_from_slice(ERC1967_CREATION_HEAD);
+    bytes.extend_from_slice(implementation.as_slice());
+    bytes.extend_from_slice(ERC1967_CREATION_TAIL);
+    bytes.extend_from_slice(ERC1967_RUNTIME);
+
+    bytes
+}
+
+/// Computes the init code of `new ERC1967Proxy(implementation, initialize(owner))`, as deployed by
+/// `SimpleAccountFactory.createAccount`.
+///
+/// ## Arguments
+/// * `proxy_creation_code` - OpenZeppelin's `ERC1967Proxy` creation code as compiled for the factory.
+/// * `implementation` - The factory's `accountImplementation`.
+/// * `owner` - The owner passed to `SimpleAccount.initialize`.
+pub fn simple_account_init_code(
+    proxy_creation_code: &[u8],
+    implementation: Address,
+    owner: Address,
+) -> Vec<u8> {
+    let init_data = Bytes::from(ISimpleAccount::initializeCall { anOwner: owner }.abi_encode());
+
+    let mut bytes = proxy_creation_code.to_vec();
+    bytes.extend_from_slice(&(implementation, init_data).abi_encode_params());
+
+    bytes
+}
+
+/// Computes the CREATE2 salt used by `LightAccountFactory.createAccount`, i.e.
+/// `keccak256(abi.encode(owner, salt))`.
+pub fn light_account_salt(owner: Address, salt: B256) -> B256 {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(owner.into_word().as_slice());
+    bytes[32..].copy_from_slice(salt.as_slice());
+
+    keccak256(bytes)
+}
+
+/// Computes the CREATE2 salt used by Kernel v3's `KernelFactory.createAccount`, i.e.
+/// `keccak256(abi.encodePacked(data, salt))`.
+pub fn kernel_salt(data: &[u8], salt: B256) -> B256 {
+    let mut bytes = Vec::with_capacity(data.len() + 32);
+    bytes.extend_from_slice(data);
+    bytes.extend_from_slice(salt.as_slice());
+
+    keccak256(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex};
+
+    #[test]
+    fn test_creation_code_erc1967_proxy() {
+        let implementation = address!("bebebebebebebebebebebebebebebebebebebebe");
+        let expected = hex!(
+            "603d3d8160223d3973bebebebebebebebebebebebebebebebebebebebe60095155f3363d3d373d3d363d7f360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc545af43d6000803e6038573d6000fd5b3d6000f3"
+        );
+
+        let creation_code = creation_code_erc1967_proxy(implementation);
+        assert_eq!(creation_code, expected);
+        assert_eq!(
+            creation_code.len(),
+            0x22 + 0x3d,
+            "The creation code must copy a 0x3d-byte runtime from offset 0x22"
+        );
+    }
+
+    #[test]
+    fn test_simple_account_init_code() {
+        let proxy_creation_code = hex!("6080604052");
+        let implementation = address!("bebebebebebebebebebebebebebebebebebebebe");
+        let owner = address!("1111111111111111111111111111111111111111");
+
+        let init_code = simple_account_init_code(&proxy_creation_code, implementation, owner);
+        assert_eq!(&init_code[..5], &proxy_creation_code);
+
+        let (decoded_implementation, init_data) =
+            <(Address, Bytes)>::abi_decode_params(&init_code[5..]).unwrap();
+        assert_eq!(decoded_implementation, implementation);
+        assert_eq!(
+            ISimpleAccount::initializeCall::abi_decode(&init_data)
+                .unwrap()
+                .anOwner,
+            owner,
+            "The proxy must be initialized with the owner"
+        );
+    }
+}
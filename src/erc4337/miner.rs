@@ -0,0 +1,226 @@
+use crate::createx::{SaltTemplate, create2_address};
+#[cfg(target_arch = "wasm32")]
+use crate::erc4337::config::AccountFactory;
+use crate::erc4337::config::{SmartAccountConfig, SmartAccountMatch, SmartAccountResult};
+use crate::pattern::{contains_predicate, prefix_predicate, suffix_predicate};
+use alloy_primitives::Address;
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+const DEFAULT_SEED: u128 = 1337;
+
+/// Mines for a `createAccount` salt that produces a smart-account address satisfying the given
+/// predicate.
+///
+/// ## Arguments
+/// * `config` - A `SmartAccountConfig` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `SmartAccountResult` containing the found salts/addresses and total iterations.
+pub fn mine_smart_account_salt(
+    config: &SmartAccountConfig,
+    predicate: &dyn Fn(Address) -> bool,
+) -> SmartAccountResult {
+    let factory = config.factory.factory();
+    let init_code_hash = config.factory.init_code_hash();
+    let mut cursor = SaltTemplate::fully_mined().cursor(config.seed.unwrap_or(DEFAULT_SEED));
+    let mut results = Vec::new();
+
+    let mut total_iterations = 0;
+
+    for i in 0..config.max_iterations {
+        total_iterations = (i + 1) as usize;
+        let salt = cursor.next_salt();
+
+        let computed_address =
+            create2_address(factory, config.factory.create2_salt(salt), init_code_hash);
+
+        if predicate(computed_address) {
+            results.push(SmartAccountMatch {
+                salt,
+                computed_address,
+            });
+
+            if results.len() >= config.max_results as usize {
+                break;
+            }
+        }
+    }
+
+    SmartAccountResult {
+        results,
+        total_iterations,
+    }
+}
+
+/// Mines for a salt that produces a smart-account address with a specific prefix.
+pub fn mine_smart_account_salt_with_prefix(
+    config: &SmartAccountConfig,
+    prefix: &[u8],
+) -> SmartAccountResult {
+    mine_smart_account_salt(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a salt that produces a smart-account address with a specific suffix.
+pub fn mine_smart_account_salt_with_suffix(
+    config: &SmartAccountConfig,
+    suffix: &[u8],
+) -> SmartAccountResult {
+    mine_smart_account_salt(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a salt that produces a smart-account address containing a specific byte sequence.
+pub fn mine_smart_account_salt_with_contains(
+    config: &SmartAccountConfig,
+    contains: &[u8],
+) -> SmartAccountResult {
+    mine_smart_account_salt(config, &contains_predicate(contains))
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_smart_account_salt_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: SmartAccountConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_smart_account_salt_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_smart_account_salt_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: SmartAccountConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_smart_account_salt_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_smart_account_salt_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: SmartAccountConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_smart_account_salt_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_smart_account_address(factory: JsValue, salt: JsValue) -> Result<JsValue, JsValue> {
+    let factory: AccountFactory =
+        from_value(factory).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let salt = from_value(salt).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    to_value(&factory.account_address(salt)).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::createx::create_address;
+    use crate::erc4337::compute::{creation_code_erc1967_proxy, light_account_salt};
+    use crate::erc4337::config::AccountFactory;
+    use alloy_primitives::{address, bytes, keccak256};
+
+    /// LightAccountFactory v2.0.0 and its account implementation.
+    const FACTORY: Address = address!("0000000000400cdfef5e2714e63d8040b700bc24");
+    const IMPLEMENTATION: Address = address!("8e8e658e22b12ada97b402ff0b044d6a325013c7");
+    /// SimpleAccountFactory v0.7.
+    const SIMPLE_ACCOUNT_FACTORY: Address = address!("91e60e0613810449d098b0b5ec8b51a0fe8c8985");
+    /// KernelFactory and Kernel v3.1.
+    const KERNEL_FACTORY: Address = address!("aac5d4240af87249b3f71bc8e4a2cae074a3e419");
+    const KERNEL_IMPLEMENTATION: Address = address!("bac849bb641841b44e965fb01a4bf5f074f84b4d");
+    const OWNER: Address = address!("1111111111111111111111111111111111111111");
+
+    fn simple_account() -> AccountFactory {
+        AccountFactory::SimpleAccount {
+            factory: SIMPLE_ACCOUNT_FACTORY,
+            implementation: create_address(SIMPLE_ACCOUNT_FACTORY, 1),
+            owner: OWNER,
+            proxy_creation_code: bytes!("6080604052"),
+        }
+    }
+
+    fn config(factory: AccountFactory) -> SmartAccountConfig {
+        SmartAccountConfig {
+            factory,
+            max_iterations: 100_000,
+            max_results: 1,
+            seed: Some(1234),
+        }
+    }
+
+    #[test]
+    fn test_mine_light_account_for_prefix() {
+        let config = config(AccountFactory::LightAccount {
+            factory: FACTORY,
+            implementation: IMPLEMENTATION,
+            owner: OWNER,
+        });
+
+        let prefix = &[0x43];
+        let mining_result = mine_smart_account_salt_with_prefix(&config, prefix);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find address starting with 0x43 within {} iterations",
+            config.max_iterations
+        );
+
+        let result = &mining_result.results[0];
+        assert!(result.computed_address.starts_with(prefix));
+        assert_eq!(
+            result.computed_address,
+            create2_address(
+                FACTORY,
+                light_account_salt(OWNER, result.salt),
+                keccak256(creation_code_erc1967_proxy(IMPLEMENTATION))
+            ),
+            "Address must match LightAccountFactory.getAddress"
+        );
+    }
+
+    #[test]
+    fn test_mine_accounts_match_account_address() {
+        let factories = [
+            simple_account(),
+            AccountFactory::Kernel {
+                factory: KERNEL_FACTORY,
+                implementation: KERNEL_IMPLEMENTATION,
+                data: bytes!("3c3b752b"),
+            },
+        ];
+
+        for factory in factories {
+            let config = config(factory);
+            let mining_result = mine_smart_account_salt(&config, &|addr| addr[0] == 0x00);
+            let result = &mining_result.results[0];
+
+            assert_eq!(
+                result.computed_address,
+                config.factory.account_address(result.salt),
+                "Mined address mismatch for {:?}",
+                config.factory
+            );
+        }
+
+        // SimpleAccountFactory passes the salt to CREATE2 as-is.
+        let salt = keccak256("salt");
+        assert_eq!(simple_account().create2_salt(salt), salt);
+    }
+}
@@ -0,0 +1,12 @@
+pub mod compute;
+pub mod config;
+pub mod miner;
+
+pub use compute::{
+    creation_code_erc1967_proxy, kernel_salt, light_account_salt, simple_account_init_code,
+};
+pub use config::{AccountFactory, SmartAccountConfig, SmartAccountMatch, SmartAccountResult};
+pub use miner::{
+    mine_smart_account_salt, mine_smart_account_salt_with_contains,
+    mine_smart_account_salt_with_prefix, mine_smart_account_salt_with_suffix,
+};
@@ -0,0 +1,96 @@
+use crate::createx::create2_address;
+use alloy_primitives::{Address, B256, U256, keccak256};
+
+const ACCOUNT_CREATION_HEAD: &[u8] = &[
+    0x3d, 0x60, 0xad, 0x80, 0x60, 0x0a, 0x3d, 0x39, 0x81, 0xf3, 0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d,
+    0x3d, 0x36, 0x3d, 0x73,
+];
+const ACCOUNT_RUNTIME_FOOTER: &[u8] = &[
+    0x5a, 0xf4, 0x3d, 0x82, 0x80, 0x3e, 0x90, 0x3d, 0x91, 0x60, 0x2b, 0x57, 0xfd, 0x5b, 0xf3,
+];
+
+/// Computes the creation code of a token bound account: an ERC-1167 proxy followed by the salt,
+/// chain id, token contract and token id.
+///
+/// See [`ERC6551Registry`](https://eips.ethereum.org/EIPS/eip-6551#registry).
+pub fn creation_code_account(
+    implementation: Address,
+    salt: B256,
+    chain_id: u64,
+    token_contract: Address,
+    token_id: U256,
+) -> [u8; 183] {
+    let mut bytes = [0u8; 183];
+    bytes[..20].copy_from_slice(ACCOUNT_CREATION_HEAD);
+    bytes[20..40].copy_from_slice(implementation.as_slice());
+    bytes[40..55].copy_from_slice(ACCOUNT_RUNTIME_FOOTER);
+    bytes[55..87].copy_from_slice(salt.as_slice());
+    bytes[87..119].copy_from_slice(&U256::from(chain_id).to_be_bytes::<32>());
+    bytes[119..151].copy_from_slice(token_contract.into_word().as_slice());
+    bytes[151..183].copy_from_slice(&token_id.to_be_bytes::<32>());
+
+    bytes
+}
+
+/// Computes the token bound account address, as `ERC6551Registry#account` does.
+pub fn account_address(
+    registry: Address,
+    implementation: Address,
+    salt: B256,
+    chain_id: u64,
+    token_contract: Address,
+    token_id: U256,
+) -> Address {
+    let creation_code =
+        creation_code_account(implementation, salt, chain_id, token_contract, token_id);
+
+    create2_address(registry, salt, keccak256(creation_code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, hex};
+
+    #[test]
+    fn test_creation_code_account() {
+        let creation_code = creation_code_account(
+            address!("bebebebebebebebebebebebebebebebebebebebe"),
+            b256!("00000000000000000000000000000000000000000000000000000000000000ff"),
+            1,
+            address!("c0ffeec0ffeec0ffeec0ffeec0ffeec0ffeec0ff"),
+            U256::from(7),
+        );
+
+        let expected = hex!(
+            "3d60ad80600a3d3981f3363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3"
+            "00000000000000000000000000000000000000000000000000000000000000ff"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "000000000000000000000000c0ffeec0ffeec0ffeec0ffeec0ffeec0ffeec0ff"
+            "0000000000000000000000000000000000000000000000000000000000000007"
+        );
+        assert_eq!(creation_code, expected);
+        assert_eq!(
+            creation_code.len() - 10,
+            0xad,
+            "The runtime code must be the 0xad bytes after the 10-byte constructor"
+        );
+    }
+
+    #[test]
+    fn test_account_address() {
+        // `ERC6551Registry.account(implementation, 0, 1, BAYC, 42)` on the canonical registry.
+        assert_eq!(
+            account_address(
+                address!("000000006551c19487814612e58fe06813775758"),
+                address!("55266d75d1a14e4572138116af39863ed6596e7f"),
+                B256::ZERO,
+                1,
+                address!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d"),
+                U256::from(42),
+            ),
+            address!("0f22b9a6cb66f995db4a76f7b9db81626131858a"),
+            "Token bound account address mismatch"
+        );
+    }
+}
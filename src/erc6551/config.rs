@@ -0,0 +1,80 @@
+use crate::createx::{Create2Match, Create2Result};
+use alloy_primitives::{Address, B256, Bytes, ChainId, U256, address};
+use alloy_sol_types::{SolCall, sol};
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_serde;
+
+sol! {
+    /// See [`IERC6551Registry`](https://eips.ethereum.org/EIPS/eip-6551#registry).
+    interface IERC6551Registry {
+        function createAccount(address implementation, bytes32 salt, uint256 chainId, address tokenContract, uint256 tokenId) external returns (address account);
+    }
+}
+
+/// The canonical ERC-6551 registry, deployed at the same address on most chains.
+pub const ERC6551_REGISTRY: Address = address!("000000006551c19487814612e58fe06813775758");
+
+const fn default_registry() -> Address {
+    ERC6551_REGISTRY
+}
+
+/// Configuration for the token bound account salt mining process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Erc6551Config {
+    /// The registry deploying the account. Defaults to the canonical registry.
+    #[serde(default = "default_registry")]
+    pub registry: Address,
+    /// The account implementation.
+    pub implementation: Address,
+    /// The chain id of the bound token.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub chain_id: ChainId,
+    /// The contract of the bound token.
+    pub token_contract: Address,
+    /// The id of the bound token.
+    pub token_id: U256,
+    /// The maximum number of attempts before giving up.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_results: u64,
+    /// Seed for the random number generator.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_option_u128")
+    )]
+    pub seed: Option<u128>,
+}
+
+impl Erc6551Config {
+    /// Encodes the `createAccount` calldata deploying the account with a mined salt.
+    pub fn create_account_calldata(&self, salt: B256) -> Bytes {
+        IERC6551Registry::createAccountCall {
+            implementation: self.implementation,
+            salt,
+            chainId: U256::from(self.chain_id),
+            tokenContract: self.token_contract,
+            tokenId: self.token_id,
+        }
+        .abi_encode()
+        .into()
+    }
+}
+
+/// A single successful match from a token bound account mining operation.
+pub type Erc6551Match = Create2Match;
+
+/// Result structure that includes token bound account matches and total iterations.
+pub type Erc6551Result = Create2Result;
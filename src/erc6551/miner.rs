@@ -0,0 +1,168 @@
+use crate::createx::SaltTemplate;
+use crate::erc6551::compute::account_address;
+use crate::erc6551::config::{Erc6551Config, Erc6551Match, Erc6551Result};
+use crate::pattern::{contains_predicate, prefix_predicate, suffix_predicate};
+use alloy_primitives::Address;
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+const DEFAULT_SEED: u128 = 1337;
+
+/// Mines for a salt that produces a token bound account address satisfying the given predicate.
+///
+/// The salt is part of the account's bytecode, so the init code is rehashed on every iteration.
+///
+/// ## Arguments
+/// * `config` - A `Erc6551Config` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `Erc6551Result` containing the found salts/addresses and total iterations.
+pub fn mine_erc6551_salt(
+    config: &Erc6551Config,
+    predicate: &dyn Fn(Address) -> bool,
+) -> Erc6551Result {
+    let mut cursor = SaltTemplate::fully_mined().cursor(config.seed.unwrap_or(DEFAULT_SEED));
+    let mut results = Vec::new();
+
+    let mut total_iterations = 0;
+
+    for i in 0..config.max_iterations {
+        total_iterations = (i + 1) as usize;
+        let salt = cursor.next_salt();
+
+        let computed_address = account_address(
+            config.registry,
+            config.implementation,
+            salt,
+            config.chain_id,
+            config.token_contract,
+            config.token_id,
+        );
+
+        if predicate(computed_address) {
+            results.push(Erc6551Match {
+                salt,
+                computed_address,
+            });
+
+            if results.len() >= config.max_results as usize {
+                break;
+            }
+        }
+    }
+
+    Erc6551Result {
+        results,
+        total_iterations,
+    }
+}
+
+/// Mines for a salt that produces a token bound account address with a specific prefix.
+pub fn mine_erc6551_salt_with_prefix(config: &Erc6551Config, prefix: &[u8]) -> Erc6551Result {
+    mine_erc6551_salt(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a salt that produces a token bound account address with a specific suffix.
+pub fn mine_erc6551_salt_with_suffix(config: &Erc6551Config, suffix: &[u8]) -> Erc6551Result {
+    mine_erc6551_salt(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a salt that produces a token bound account address containing a specific byte
+/// sequence.
+pub fn mine_erc6551_salt_with_contains(config: &Erc6551Config, contains: &[u8]) -> Erc6551Result {
+    mine_erc6551_salt(config, &contains_predicate(contains))
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_erc6551_salt_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: Erc6551Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_erc6551_salt_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_erc6551_salt_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: Erc6551Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_erc6551_salt_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_erc6551_salt_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: Erc6551Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_erc6551_salt_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::createx::create2_address;
+    use crate::erc6551::compute::creation_code_account;
+    use crate::erc6551::config::ERC6551_REGISTRY;
+    use alloy_primitives::{U256, address, keccak256};
+
+    #[test]
+    fn test_mine_erc6551_for_suffix() {
+        let config: Erc6551Config = serde_json::from_value(serde_json::json!({
+            "implementation": "0x55266d75d1a14e4572138116af39863ed6596e7f",
+            "chain_id": 1,
+            "token_contract": "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d",
+            "token_id": "0x2a",
+            "max_iterations": 1_000_000,
+            "max_results": 1,
+            "seed": 1234
+        }))
+        .unwrap();
+        assert_eq!(
+            config.registry, ERC6551_REGISTRY,
+            "The canonical registry should be the default"
+        );
+
+        let suffix = &[0x65, 0x51];
+        let mining_result = mine_erc6551_salt_with_suffix(&config, suffix);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find address ending with 0x6551 within {} iterations",
+            config.max_iterations
+        );
+
+        let result = &mining_result.results[0];
+        assert!(result.computed_address.ends_with(suffix));
+
+        let creation_code = creation_code_account(
+            config.implementation,
+            result.salt,
+            1,
+            address!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d"),
+            U256::from(42),
+        );
+        assert_eq!(
+            result.computed_address,
+            create2_address(ERC6551_REGISTRY, result.salt, keccak256(creation_code)),
+            "Address must match ERC6551Registry.account"
+        );
+    }
+}
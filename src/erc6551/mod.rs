@@ -0,0 +1,10 @@
+pub mod compute;
+pub mod config;
+pub mod miner;
+
+pub use compute::{account_address, creation_code_account};
+pub use config::{ERC6551_REGISTRY, Erc6551Config, Erc6551Match, Erc6551Result};
+pub use miner::{
+    mine_erc6551_salt, mine_erc6551_salt_with_contains, mine_erc6551_salt_with_prefix,
+    mine_erc6551_salt_with_suffix,
+};
@@ -0,0 +1,308 @@
+use crate::eulerswap::config::{
+    EulerSwapConfig, EulerSwapError, EulerSwapMatch, EulerSwapParams, EulerSwapPoolParams,
+    EulerSwapVersion, IEulerSwap, IEulerSwapFactory,
+};
+use alloy_primitives::{Address, Bytes, U256, aliases::U112};
+use alloy_sol_types::{SolCall, sol};
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+sol! {
+    /// The subset of the Ethereum Vault Connector used to deploy pools.
+    ///
+    /// See [`IEVC`](https://github.com/euler-xyz/ethereum-vault-connector/blob/master/src/interfaces/IEthereumVaultConnector.sol).
+    interface IEVC {
+        struct BatchItem {
+            address targetContract;
+            address onBehalfOfAccount;
+            uint256 value;
+            bytes data;
+        }
+
+        function batch(BatchItem[] calldata items) external payable;
+        function setAccountOperator(address account, address operator, bool authorized) external payable;
+    }
+}
+
+/// The reserves the pool is activated with, `IEulerSwap.InitialState`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EulerSwapInitialState {
+    pub curr_reserve0: U112,
+    pub curr_reserve1: U112,
+}
+
+/// Returns the v1 params of a config, checking that the match belongs to it.
+fn deployable_params<'a>(
+    config: &'a EulerSwapConfig,
+    result: &EulerSwapMatch,
+) -> Result<&'a EulerSwapParams, EulerSwapError> {
+    let EulerSwapPoolParams::V1(params) = &config.pool_params else {
+        return Err(EulerSwapError::UnsupportedDeployment);
+    };
+    if config.version != EulerSwapVersion::V1 {
+        return Err(EulerSwapError::UnsupportedDeployment);
+    }
+    config.validate()?;
+
+    let expected = config.pool_address(result.salt);
+    if result.computed_address != expected {
+        return Err(EulerSwapError::PoolAddressMismatch {
+            expected,
+            computed: result.computed_address,
+        });
+    }
+    Ok(params)
+}
+
+/// Encodes the `EulerSwapFactory.deployPool` calldata for a mined salt.
+///
+/// The factory only accepts the call from the pool's `eulerAccount`, see
+/// [`deploy_pool_batch_calldata`] to send it through the EVC.
+///
+/// ## Arguments
+/// * `config` - The `EulerSwapConfig` the salt was mined with.
+/// * `result` - The match returned by `mine_eulerswap_salt`.
+/// * `initial_state` - The reserves the pool is activated with.
+///
+/// ## Returns
+/// The calldata to send to the factory, or an error if the config is not deployable.
+pub fn deploy_pool_calldata(
+    config: &EulerSwapConfig,
+    result: &EulerSwapMatch,
+    initial_state: &EulerSwapInitialState,
+) -> Result<Bytes, EulerSwapError> {
+    let params = deployable_params(config, result)?;
+
+    Ok(encode_deploy_pool(params, result, initial_state))
+}
+
+fn encode_deploy_pool(
+    params: &EulerSwapParams,
+    result: &EulerSwapMatch,
+    initial_state: &EulerSwapInitialState,
+) -> Bytes {
+    IEulerSwapFactory::deployPoolCall {
+        params: params.to_sol(),
+        initialState: IEulerSwap::InitialState {
+            currReserve0: initial_state.curr_reserve0,
+            currReserve1: initial_state.curr_reserve1,
+        },
+        salt: result.salt,
+    }
+    .abi_encode()
+    .into()
+}
+
+/// Encodes an `EVC.batch` that installs the pool as operator of the `eulerAccount` and
+/// deploys it on the account's behalf.
+///
+/// ## Arguments
+/// * `config` - The `EulerSwapConfig` the salt was mined with.
+/// * `result` - The match returned by `mine_eulerswap_salt`.
+/// * `initial_state` - The reserves the pool is activated with.
+/// * `evc` - The Ethereum Vault Connector of the chain.
+///
+/// ## Returns
+/// The calldata to send to the EVC from the `eulerAccount` owner, or an error if the config is
+/// not deployable.
+pub fn deploy_pool_batch_calldata(
+    config: &EulerSwapConfig,
+    result: &EulerSwapMatch,
+    initial_state: &EulerSwapInitialState,
+    evc: Address,
+) -> Result<Bytes, EulerSwapError> {
+    let params = deployable_params(config, result)?;
+    let euler_account = params.euler_account;
+
+    let items = vec![
+        IEVC::BatchItem {
+            targetContract: evc,
+            onBehalfOfAccount: Address::ZERO,
+            value: U256::ZERO,
+            data: IEVC::setAccountOperatorCall {
+                account: euler_account,
+                operator: result.computed_address,
+                authorized: true,
+            }
+            .abi_encode()
+            .into(),
+        },
+        IEVC::BatchItem {
+            targetContract: config.factory,
+            onBehalfOfAccount: euler_account,
+            value: U256::ZERO,
+            data: encode_deploy_pool(params, result, initial_state),
+        },
+    ];
+
+    Ok(IEVC::batchCall { items }.abi_encode().into())
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_eulerswap_pool_address(config: JsValue, salt: JsValue) -> Result<JsValue, JsValue> {
+    let config: EulerSwapConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let salt = from_value(salt).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    to_value(&config.pool_address(salt)).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_eulerswap_deploy_pool_calldata(
+    config: JsValue,
+    result: JsValue,
+    initial_state: JsValue,
+) -> Result<JsValue, JsValue> {
+    let config: EulerSwapConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let result: EulerSwapMatch =
+        from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let initial_state: EulerSwapInitialState =
+        from_value(initial_state).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let calldata = deploy_pool_calldata(&config, &result, &initial_state)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&calldata).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_eulerswap_deploy_pool_batch_calldata(
+    config: JsValue,
+    result: JsValue,
+    initial_state: JsValue,
+    evc: JsValue,
+) -> Result<JsValue, JsValue> {
+    let config: EulerSwapConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let result: EulerSwapMatch =
+        from_value(result).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let initial_state: EulerSwapInitialState =
+        from_value(initial_state).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let evc: Address = from_value(evc).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let calldata = deploy_pool_batch_calldata(&config, &result, &initial_state, evc)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&calldata).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    const EVC: Address = address!("0c9a3dd6b8f28529d72d7f9ce918d493519ee383");
+
+    fn config() -> EulerSwapConfig {
+        EulerSwapConfig {
+            factory: address!("fb9fe66472917f0f8966506a3bf831ac0c10cad4"),
+            eulerswap_impl: address!("f5d35536482f62c9031b4d6bd34724671bce33d1"),
+            version: EulerSwapVersion::V1,
+            pool_params: EulerSwapParams {
+                vault0: address!("797dd80692c3b2dadabce8e30c07fde5307d48a9"),
+                vault1: address!("313603fa690301b0caeef8069c065862f9162162"),
+                euler_account: address!("0afbf798467f9b3b97f90d05bf7df592d89a6cf0"),
+                equilibrium_reserve0: U112::from(68925668118_u128),
+                equilibrium_reserve1: U112::from(73751769958_u128),
+                price_x: U256::from(1000000),
+                price_y: U256::from(1000000),
+                concentration_x: U256::from(999000000000000100_u128),
+                concentration_y: U256::from(999000000000000100_u128),
+                fee: U256::from(10000000000000_u128),
+                protocol_fee: U256::ZERO,
+                protocol_fee_recipient: Address::ZERO,
+            }
+            .into(),
+            max_iterations: 1,
+            max_results: 1,
+            seed: None,
+        }
+    }
+
+    fn mined() -> EulerSwapMatch {
+        EulerSwapMatch {
+            salt: b256!("ad9a4b2ded54a895eb0ed679849a84bbe50609a3b39654ad9616cd0c24b4107b"),
+            computed_address: address!("8863dc83c8eee1b67e61523ffcb928f40580e8a8"),
+        }
+    }
+
+    const INITIAL_STATE: EulerSwapInitialState = EulerSwapInitialState {
+        curr_reserve0: U112::from_limbs([68925668118, 0]),
+        curr_reserve1: U112::from_limbs([73751769958, 0]),
+    };
+
+    #[test]
+    fn test_deploy_pool_calldata() {
+        let config = config();
+        assert_eq!(config.pool_address(mined().salt), mined().computed_address);
+
+        let calldata = deploy_pool_calldata(&config, &mined(), &INITIAL_STATE).unwrap();
+        let call = IEulerSwapFactory::deployPoolCall::abi_decode(&calldata).unwrap();
+        assert_eq!(call.salt, mined().salt);
+        assert_eq!(call.initialState.currReserve0, INITIAL_STATE.curr_reserve0);
+        assert_eq!(call.initialState.currReserve1, INITIAL_STATE.curr_reserve1);
+        assert_eq!(
+            call.params.eulerAccount,
+            address!("0afbf798467f9b3b97f90d05bf7df592d89a6cf0")
+        );
+        assert_eq!(
+            calldata[4..4 + 12 * 32],
+            config.pool_params.abi_encode()[..],
+            "The params are encoded in place as the proxy metadata"
+        );
+    }
+
+    #[test]
+    fn test_deploy_pool_batch_calldata() {
+        let config = config();
+        let euler_account = address!("0afbf798467f9b3b97f90d05bf7df592d89a6cf0");
+
+        let calldata = deploy_pool_batch_calldata(&config, &mined(), &INITIAL_STATE, EVC).unwrap();
+        let items = IEVC::batchCall::abi_decode(&calldata).unwrap().items;
+        assert_eq!(items.len(), 2);
+
+        assert_eq!(items[0].targetContract, EVC);
+        assert_eq!(
+            items[0].onBehalfOfAccount,
+            Address::ZERO,
+            "EVC self-calls must not set an account"
+        );
+        let operator = IEVC::setAccountOperatorCall::abi_decode(&items[0].data).unwrap();
+        assert_eq!(operator.account, euler_account);
+        assert_eq!(operator.operator, mined().computed_address);
+        assert!(operator.authorized);
+
+        assert_eq!(items[1].targetContract, config.factory);
+        assert_eq!(items[1].onBehalfOfAccount, euler_account);
+        assert_eq!(
+            items[1].data,
+            deploy_pool_calldata(&config, &mined(), &INITIAL_STATE).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deploy_pool_calldata_rejects_mismatches() {
+        let mut config = config();
+        let mut result = mined();
+        result.computed_address = Address::ZERO;
+        assert_eq!(
+            deploy_pool_calldata(&config, &result, &INITIAL_STATE),
+            Err(EulerSwapError::PoolAddressMismatch {
+                expected: mined().computed_address,
+                computed: Address::ZERO,
+            })
+        );
+
+        config.pool_params = EulerSwapPoolParams::Encoded(config.pool_params.abi_encode().into());
+        assert_eq!(
+            deploy_pool_calldata(&config, &mined(), &INITIAL_STATE),
+            Err(EulerSwapError::UnsupportedDeployment)
+        );
+    }
+}
@@ -1,14 +1,31 @@
 use crate::createx::create2_address;
-use crate::eulerswap::config::EulerSwapParams;
+use crate::eulerswap::config::{EulerSwapDeployment, EulerSwapParams, EulerSwapVersion};
 use alloy_primitives::{Address, B256, keccak256};
 
-const BYTECODE_HEAD: &[u8] = &[
-    0x60, 0x0b, 0x38, 0x03, 0x80, 0x60, 0x0b, 0x3d, 0x39, 0x3d, 0xf3, 0x36, 0x3d, 0x3d, 0x37, 0x3d,
-    0x3d, 0x3d, 0x3d, 0x60, 0x36, 0x80, 0x38, 0x03, 0x80, 0x91, 0x36, 0x39, 0x36, 0x01, 0x3d, 0x73,
-];
-const BYTECODE_TAIL: &[u8] = &[
-    0x5a, 0xf4, 0x3d, 0x3d, 0x93, 0x80, 0x3e, 0x60, 0x34, 0x57, 0xfd, 0x5b, 0xf3,
-];
+pub use crate::meta_proxy::creation_code_meta_proxy;
+
+/// Computes the creation code of an EulerSwap pool proxy for a release.
+///
+/// ## Arguments
+/// * `deployment` - The proxy bytecode of the release.
+/// * `eulerswap_impl` - The EulerSwap implementation address.
+/// * `pool_params` - The encoded pool parameters appended to the proxy.
+///
+/// ## Returns
+/// The creation code as a `Vec<u8>`.
+pub fn creation_code_eulerswap(
+    deployment: &EulerSwapDeployment,
+    eulerswap_impl: Address,
+    pool_params: &[u8],
+) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&deployment.proxy_bytecode_head);
+    bytes.extend_from_slice(eulerswap_impl.as_slice());
+    bytes.extend_from_slice(&deployment.proxy_bytecode_tail);
+    bytes.extend_from_slice(pool_params);
+
+    bytes
+}
 
 /// Computes the address of the EulerSwap pool.
 pub fn eulerswap_address(
@@ -17,27 +34,32 @@ pub fn eulerswap_address(
     pool_params: EulerSwapParams,
     salt: B256,
 ) -> Address {
-    let creation_code = creation_code_meta_proxy(eulerswap_impl, &pool_params.abi_encode());
-    let init_code_hash = keccak256(creation_code);
-
-    create2_address(factory, salt, init_code_hash)
+    eulerswap_address_for_version(
+        factory,
+        eulerswap_impl,
+        &EulerSwapVersion::V1,
+        &pool_params.abi_encode(),
+        salt,
+    )
 }
 
-/// Computes the creation code for the EulerSwap pool.
-pub fn creation_code_meta_proxy(target_contract: Address, metadata: &[u8]) -> Vec<u8> {
-    let mut bytes = Vec::new();
-    bytes.extend_from_slice(BYTECODE_HEAD);
-    bytes.extend_from_slice(target_contract.as_slice());
-    bytes.extend_from_slice(BYTECODE_TAIL);
-    bytes.extend_from_slice(metadata);
+/// Computes the address of an EulerSwap pool deployed by a specific release.
+pub fn eulerswap_address_for_version(
+    factory: Address,
+    eulerswap_impl: Address,
+    version: &EulerSwapVersion,
+    pool_params: &[u8],
+    salt: B256,
+) -> Address {
+    let creation_code = creation_code_eulerswap(&version.deployment(), eulerswap_impl, pool_params);
 
-    bytes
+    create2_address(factory, salt, keccak256(creation_code))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{U256, address, aliases::U112, b256};
+    use alloy_primitives::{Bytes, U256, address, aliases::U112, b256, hex};
 
     #[test]
     fn test_eulerswap_address() {
@@ -68,4 +90,37 @@ mod tests {
             factory, eulerswap_impl, pool_params, salt
         );
     }
+
+    #[test]
+    fn test_creation_code_for_custom_version() {
+        let eulerswap_impl = address!("bebebebebebebebebebebebebebebebebebebebe");
+        let params = hex!("c0ffee");
+
+        assert_eq!(
+            creation_code_eulerswap(&EulerSwapVersion::V1.deployment(), eulerswap_impl, &params),
+            creation_code_meta_proxy(eulerswap_impl, &params),
+            "v1 pools are ERC-3448 meta-proxies"
+        );
+
+        let custom = EulerSwapVersion::Custom(EulerSwapDeployment {
+            hook_permissions: Default::default(),
+            proxy_bytecode_head: Bytes::from_static(&hex!("aa")),
+            proxy_bytecode_tail: Bytes::from_static(&hex!("bbcc")),
+        });
+        let creation_code = creation_code_eulerswap(&custom.deployment(), eulerswap_impl, &params);
+        assert_eq!(
+            creation_code,
+            hex!("aabebebebebebebebebebebebebebebebebebebebebbccc0ffee")
+        );
+        assert_eq!(
+            eulerswap_address_for_version(
+                Address::ZERO,
+                eulerswap_impl,
+                &custom,
+                &params,
+                B256::ZERO
+            ),
+            create2_address(Address::ZERO, B256::ZERO, keccak256(creation_code))
+        );
+    }
 }
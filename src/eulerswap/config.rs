@@ -1,11 +1,98 @@
-use crate::univ4_hook::{V4HookMatch, V4HookResult};
-use alloy_primitives::{Address, U256, aliases::U112};
+use crate::eulerswap::compute::eulerswap_address_for_version;
+use crate::meta_proxy::compute::{BYTECODE_HEAD, BYTECODE_TAIL};
+use crate::univ4_hook::{V4HookError, V4HookMatch, V4HookPermissions, V4HookResult};
+use alloy_primitives::{Address, B256, Bytes, U256, aliases::U112, uint};
 use alloy_sol_types::{SolValue, sol};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 #[cfg(target_arch = "wasm32")]
 use crate::wasm_serde;
 
+/// The hook permissions returned by `EulerSwap.getHookPermissions` in EulerSwap v1.
+pub const EULERSWAP_V1_HOOK_PERMISSIONS: V4HookPermissions = V4HookPermissions {
+    before_initialize: true,
+    before_swap: true,
+    before_swap_return_delta: true,
+    before_donate: true,
+    before_add_liquidity: true,
+
+    after_initialize: false,
+    after_add_liquidity: false,
+    before_remove_liquidity: false,
+    after_remove_liquidity: false,
+    after_swap: false,
+    after_donate: false,
+    after_swap_return_delta: false,
+    after_add_liquidity_return_delta: false,
+    after_remove_liquidity_return_delta: false,
+};
+
+sol! {
+    /// The v1 `IEulerSwap` structs.
+    interface IEulerSwap {
+        struct Params {
+            address vault0;
+            address vault1;
+            address eulerAccount;
+            uint112 equilibriumReserve0;
+            uint112 equilibriumReserve1;
+            uint256 priceX;
+            uint256 priceY;
+            uint256 concentrationX;
+            uint256 concentrationY;
+            uint256 fee;
+            uint256 protocolFee;
+            address protocolFeeRecipient;
+        }
+
+        struct InitialState {
+            uint112 currReserve0;
+            uint112 currReserve1;
+        }
+    }
+
+    /// See [`EulerSwapFactory`](https://github.com/euler-xyz/euler-swap/blob/master/src/EulerSwapFactory.sol).
+    interface IEulerSwapFactory {
+        function deployPool(IEulerSwap.Params memory params, IEulerSwap.InitialState memory initialState, bytes32 salt) external returns (address);
+    }
+}
+
+/// The largest `priceX`/`priceY` accepted by `EulerSwap.activate`.
+pub const MAX_PRICE: U256 = uint!(10000000000000000000000000_U256);
+
+/// The largest `concentrationX`/`concentrationY` accepted by `EulerSwap.activate` (1e18).
+pub const MAX_CONCENTRATION: U256 = uint!(1000000000000000000_U256);
+
+/// The exclusive upper bound of `fee` accepted by `EulerSwap.activate` (1e18, i.e. 100%).
+pub const MAX_FEE: U256 = uint!(1000000000000000000_U256);
+
+/// Errors raised when EulerSwap would reject the pool parameters or hook permissions.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EulerSwapError {
+    #[error("vault0 and vault1 are both {0}")]
+    IdenticalVaults(Address),
+    #[error("priceX and priceY must be non-zero")]
+    ZeroPrice,
+    #[error("price {0} exceeds the maximum of {MAX_PRICE}")]
+    PriceTooLarge(U256),
+    #[error("concentration {0} exceeds the maximum of {MAX_CONCENTRATION}")]
+    ConcentrationTooLarge(U256),
+    #[error("fee {0} must be below {MAX_FEE}")]
+    FeeTooLarge(U256),
+    #[error("deployPool calldata needs the v1 release and the v1 params struct")]
+    UnsupportedDeployment,
+    #[error("the v1 release takes the v1 params struct, custom releases take encoded params")]
+    ParamsLayoutMismatch,
+    #[error("match address {computed} differs from the pool address {expected} for this config")]
+    PoolAddressMismatch {
+        expected: Address,
+        computed: Address,
+    },
+    #[error(transparent)]
+    HookPermissions(#[from] V4HookError),
+}
+
 /// Parameters for the EulerSwap pool.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EulerSwapParams {
@@ -24,24 +111,39 @@ pub struct EulerSwapParams {
 }
 
 impl EulerSwapParams {
-    pub fn abi_encode(&self) -> Vec<u8> {
-        sol! {
-            struct Params {
-                address vault0;
-                address vault1;
-                address eulerAccount;
-                uint112 equilibriumReserve0;
-                uint112 equilibriumReserve1;
-                uint256 priceX;
-                uint256 priceY;
-                uint256 concentrationX;
-                uint256 concentrationY;
-                uint256 fee;
-                uint256 protocolFee;
-                address protocolFeeRecipient;
+    /// Validates the parameters against the checks in `EulerSwap.activate`.
+    ///
+    /// Checks that need chain state, such as the vault asset order and the factory's protocol
+    /// fee, are left to the contracts.
+    pub fn validate(&self) -> Result<(), EulerSwapError> {
+        if self.vault0 == self.vault1 {
+            return Err(EulerSwapError::IdenticalVaults(self.vault0));
+        }
+        if self.fee >= MAX_FEE {
+            return Err(EulerSwapError::FeeTooLarge(self.fee));
+        }
+        for price in [self.price_x, self.price_y] {
+            if price.is_zero() {
+                return Err(EulerSwapError::ZeroPrice);
+            }
+            if price > MAX_PRICE {
+                return Err(EulerSwapError::PriceTooLarge(price));
             }
         }
-        let params = Params {
+        for concentration in [self.concentration_x, self.concentration_y] {
+            if concentration > MAX_CONCENTRATION {
+                return Err(EulerSwapError::ConcentrationTooLarge(concentration));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn abi_encode(&self) -> Vec<u8> {
+        self.to_sol().abi_encode()
+    }
+
+    pub(crate) fn to_sol(&self) -> IEulerSwap::Params {
+        IEulerSwap::Params {
             vault0: self.vault0,
             vault1: self.vault1,
             eulerAccount: self.euler_account,
@@ -54,8 +156,69 @@ impl EulerSwapParams {
             fee: self.fee,
             protocolFee: self.protocol_fee,
             protocolFeeRecipient: self.protocol_fee_recipient,
-        };
-        Params::abi_encode(&params)
+        }
+    }
+}
+
+/// The pool parameters stored as proxy metadata, in the layout of the targeted release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EulerSwapPoolParams {
+    /// The v1 `IEulerSwap.Params` struct.
+    V1(Box<EulerSwapParams>),
+    /// Pre-encoded parameters for releases with a different struct layout.
+    Encoded(Bytes),
+}
+
+impl EulerSwapPoolParams {
+    /// Returns the parameters as appended to the proxy creation code.
+    pub fn abi_encode(&self) -> Vec<u8> {
+        match self {
+            Self::V1(params) => params.abi_encode(),
+            Self::Encoded(encoded) => encoded.to_vec(),
+        }
+    }
+}
+
+impl From<EulerSwapParams> for EulerSwapPoolParams {
+    fn from(params: EulerSwapParams) -> Self {
+        Self::V1(Box::new(params))
+    }
+}
+
+/// The hook permissions and pool proxy of an EulerSwap release.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EulerSwapDeployment {
+    /// The permissions returned by the implementation's `getHookPermissions`.
+    pub hook_permissions: V4HookPermissions,
+    /// The proxy creation code preceding the implementation address.
+    pub proxy_bytecode_head: Bytes,
+    /// The proxy creation code between the implementation address and the pool parameters.
+    pub proxy_bytecode_tail: Bytes,
+}
+
+/// The EulerSwap release a pool is deployed with.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EulerSwapVersion {
+    /// EulerSwap v1, whose factory deploys ERC-3448 meta-proxies.
+    #[default]
+    V1,
+    /// Any other release, given by its hook permissions and proxy bytecode.
+    Custom(EulerSwapDeployment),
+}
+
+impl EulerSwapVersion {
+    /// Returns the hook permissions and proxy bytecode of the release.
+    pub fn deployment(&self) -> EulerSwapDeployment {
+        match self {
+            Self::V1 => EulerSwapDeployment {
+                hook_permissions: EULERSWAP_V1_HOOK_PERMISSIONS,
+                proxy_bytecode_head: Bytes::from_static(BYTECODE_HEAD),
+                proxy_bytecode_tail: Bytes::from_static(BYTECODE_TAIL),
+            },
+            Self::Custom(deployment) => deployment.clone(),
+        }
     }
 }
 
@@ -65,8 +228,12 @@ pub struct EulerSwapConfig {
     pub factory: Address,
     /// The EulerSwap implementation address.
     pub eulerswap_impl: Address,
-    /// The IEulerSwap.Params struct pool parameters.
-    pub pool_params: EulerSwapParams,
+    /// The EulerSwap release of the factory, v1 by default.
+    #[serde(default)]
+    pub version: EulerSwapVersion,
+    /// The pool parameters, the v1 `IEulerSwap.Params` struct for the v1 release or pre-encoded
+    /// bytes for a custom one.
+    pub pool_params: EulerSwapPoolParams,
     #[cfg_attr(
         target_arch = "wasm32",
         serde(deserialize_with = "wasm_serde::deserialize_u64")
@@ -86,8 +253,171 @@ pub struct EulerSwapConfig {
     pub seed: Option<u128>,
 }
 
+impl EulerSwapConfig {
+    /// Validates the release's hook permissions and the pool parameters, which must be the v1
+    /// struct for the v1 release and pre-encoded for a custom release.
+    pub fn validate(&self) -> Result<(), EulerSwapError> {
+        self.version.deployment().hook_permissions.validate()?;
+        match (&self.version, &self.pool_params) {
+            (EulerSwapVersion::V1, EulerSwapPoolParams::V1(params)) => params.validate()?,
+            (EulerSwapVersion::Custom(_), EulerSwapPoolParams::Encoded(_)) => {}
+            _ => return Err(EulerSwapError::ParamsLayoutMismatch),
+        }
+        Ok(())
+    }
+
+    /// Computes the address of the pool deployed with `salt`.
+    pub fn pool_address(&self, salt: B256) -> Address {
+        eulerswap_address_for_version(
+            self.factory,
+            self.eulerswap_impl,
+            &self.version,
+            &self.pool_params.abi_encode(),
+            salt,
+        )
+    }
+}
+
 /// A single successful match from a EulerSwap mining operation.
 pub type EulerSwapMatch = V4HookMatch;
 
 /// Result structure that includes EulerSwap matches and total iterations.
 pub type EulerSwapResult = V4HookResult;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    fn params() -> EulerSwapParams {
+        EulerSwapParams {
+            vault0: address!("797dd80692c3b2dadabce8e30c07fde5307d48a9"),
+            vault1: address!("313603fa690301b0caeef8069c065862f9162162"),
+            euler_account: address!("0afbf798467f9b3b97f90d05bf7df592d89a6cf0"),
+            equilibrium_reserve0: U112::from(68925668118_u128),
+            equilibrium_reserve1: U112::from(73751769958_u128),
+            price_x: U256::from(1000000),
+            price_y: U256::from(1000000),
+            concentration_x: U256::from(999000000000000100_u128),
+            concentration_y: U256::from(999000000000000100_u128),
+            fee: U256::from(10000000000000_u128),
+            protocol_fee: U256::ZERO,
+            protocol_fee_recipient: Address::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_params_validation() {
+        assert_eq!(params().validate(), Ok(()));
+        assert_eq!(
+            EulerSwapParams {
+                concentration_x: MAX_CONCENTRATION,
+                concentration_y: U256::ZERO,
+                fee: U256::ZERO,
+                price_x: MAX_PRICE,
+                ..params()
+            }
+            .validate(),
+            Ok(()),
+            "The bounds themselves are accepted"
+        );
+
+        assert_eq!(
+            EulerSwapParams {
+                vault1: params().vault0,
+                ..params()
+            }
+            .validate(),
+            Err(EulerSwapError::IdenticalVaults(params().vault0))
+        );
+        assert_eq!(
+            EulerSwapParams {
+                fee: MAX_FEE,
+                ..params()
+            }
+            .validate(),
+            Err(EulerSwapError::FeeTooLarge(MAX_FEE))
+        );
+        assert_eq!(
+            EulerSwapParams {
+                price_y: U256::ZERO,
+                ..params()
+            }
+            .validate(),
+            Err(EulerSwapError::ZeroPrice)
+        );
+        assert_eq!(
+            EulerSwapParams {
+                price_x: MAX_PRICE + U256::from(1),
+                ..params()
+            }
+            .validate(),
+            Err(EulerSwapError::PriceTooLarge(MAX_PRICE + U256::from(1)))
+        );
+        assert_eq!(
+            EulerSwapParams {
+                concentration_y: MAX_CONCENTRATION + U256::from(1),
+                ..params()
+            }
+            .validate(),
+            Err(EulerSwapError::ConcentrationTooLarge(
+                MAX_CONCENTRATION + U256::from(1)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let mut config = EulerSwapConfig {
+            factory: Address::ZERO,
+            eulerswap_impl: Address::ZERO,
+            version: EulerSwapVersion::V1,
+            pool_params: EulerSwapParams {
+                fee: MAX_FEE,
+                ..params()
+            }
+            .into(),
+            max_iterations: 1,
+            max_results: 1,
+            seed: None,
+        };
+        assert_eq!(config.validate(), Err(EulerSwapError::FeeTooLarge(MAX_FEE)));
+
+        config.pool_params = EulerSwapPoolParams::Encoded(params().abi_encode().into());
+        assert_eq!(
+            config.validate(),
+            Err(EulerSwapError::ParamsLayoutMismatch),
+            "The v1 release must use the typed params"
+        );
+
+        let custom = EulerSwapDeployment {
+            hook_permissions: EULERSWAP_V1_HOOK_PERMISSIONS,
+            proxy_bytecode_head: Bytes::new(),
+            proxy_bytecode_tail: Bytes::new(),
+        };
+        config.version = EulerSwapVersion::Custom(custom.clone());
+        assert_eq!(config.validate(), Ok(()), "Encoded params are not checked");
+
+        config.pool_params = params().into();
+        assert_eq!(
+            config.validate(),
+            Err(EulerSwapError::ParamsLayoutMismatch),
+            "Custom releases must encode their own params layout"
+        );
+
+        config.pool_params = EulerSwapPoolParams::Encoded(Bytes::new());
+        config.version = EulerSwapVersion::Custom(EulerSwapDeployment {
+            hook_permissions: V4HookPermissions {
+                after_swap_return_delta: true,
+                ..Default::default()
+            },
+            ..custom
+        });
+        assert!(matches!(
+            config.validate(),
+            Err(EulerSwapError::HookPermissions(
+                V4HookError::ReturnDeltaWithoutParent { .. }
+            ))
+        ));
+    }
+}
@@ -1,52 +1,53 @@
-use crate::eulerswap::compute::creation_code_meta_proxy;
-use crate::eulerswap::config::{EulerSwapConfig, EulerSwapResult};
-use crate::univ4_hook::{V4HookConfig, V4HookPermissions, mine_v4_hook_salt};
+use crate::eulerswap::compute::creation_code_eulerswap;
+use crate::eulerswap::config::{EulerSwapConfig, EulerSwapError, EulerSwapResult};
+use crate::univ4_hook::{V4HookConfig, try_mine_v4_hook_salt};
 use alloy_primitives::keccak256;
 #[cfg(target_arch = "wasm32")]
 use serde_wasm_bindgen::{from_value, to_value};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
-const EULERSWAP_HOOK_PERMISSIONS: V4HookPermissions = V4HookPermissions {
-    before_initialize: true,
-    before_swap: true,
-    before_swap_return_delta: true,
-    before_donate: true,
-    before_add_liquidity: true,
-
-    after_initialize: false,
-    after_add_liquidity: false,
-    before_remove_liquidity: false,
-    after_remove_liquidity: false,
-    after_swap: false,
-    after_donate: false,
-    after_swap_return_delta: false,
-    after_add_liquidity_return_delta: false,
-    after_remove_liquidity_return_delta: false,
-};
-
 /// Mines for a salt that produces a Uniswap v4 hook address satisfying the given permission flags.
 ///
+/// The hook permissions and proxy bytecode are taken from the configured EulerSwap release.
+///
 /// ## Arguments
 /// * `config` - A `EulerSwapConfig` struct defining the mining parameters.
 ///
 /// ## Returns
 /// A `EulerSwapResult` containing the found salts/addresses and total iterations.
+///
+/// ## Panics
+/// If the config is invalid, see `try_mine_eulerswap_salt`.
 pub fn mine_eulerswap_salt(config: &EulerSwapConfig) -> EulerSwapResult {
+    try_mine_eulerswap_salt(config).unwrap_or_else(|e| panic!("Invalid config: {e}"))
+}
+
+/// Like `mine_eulerswap_salt`, but returns an error instead of panicking on an invalid config.
+///
+/// ## Returns
+/// A `EulerSwapResult` containing the found salts/addresses and total iterations, or the
+/// `EulerSwapError` EulerSwap or the `PoolManager` would reject the pool with.
+pub fn try_mine_eulerswap_salt(
+    config: &EulerSwapConfig,
+) -> Result<EulerSwapResult, EulerSwapError> {
+    config.validate()?;
+    let deployment = config.version.deployment();
     let pool_params = config.pool_params.abi_encode();
-    let creation_code = creation_code_meta_proxy(config.eulerswap_impl, &pool_params);
+    let creation_code = creation_code_eulerswap(&deployment, config.eulerswap_impl, &pool_params);
     let init_code_hash = keccak256(creation_code);
 
     let v4_hook_config = V4HookConfig {
         deployer: config.factory,
+        dynamic_fee: false,
         init_code_hash,
         max_iterations: config.max_iterations,
         max_results: config.max_results,
         seed: config.seed,
-        permissions: EULERSWAP_HOOK_PERMISSIONS,
+        permissions: deployment.hook_permissions.into(),
     };
 
-    mine_v4_hook_salt(&v4_hook_config)
+    Ok(try_mine_v4_hook_salt(&v4_hook_config)?)
 }
 
 // WASM wrapper functions
@@ -55,7 +56,105 @@ pub fn mine_eulerswap_salt(config: &EulerSwapConfig) -> EulerSwapResult {
 pub fn wasm_mine_eulerswap_salt(config: JsValue) -> Result<JsValue, JsValue> {
     let config: EulerSwapConfig =
         from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
-
-    let mining_result = mine_eulerswap_salt(&config);
+    let mining_result =
+        try_mine_eulerswap_salt(&config).map_err(|e| JsValue::from_str(&e.to_string()))?;
     to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eulerswap::compute::eulerswap_address_for_version;
+    use crate::eulerswap::config::EulerSwapParams;
+    use crate::univ4_hook::V4HookPermissions;
+    use alloy_primitives::{Address, U256, address, aliases::U112};
+
+    #[test]
+    fn test_mine_eulerswap_for_custom_version() {
+        let config: EulerSwapConfig = serde_json::from_value(serde_json::json!({
+            "factory": "0x2222222222222222222222222222222222222222",
+            "eulerswap_impl": "0x3333333333333333333333333333333333333333",
+            "version": {
+                "custom": {
+                    "hook_permissions": ["beforeSwap", "afterSwap"],
+                    "proxy_bytecode_head": "0x600b380380600b3d393df3",
+                    "proxy_bytecode_tail": "0x5af4"
+                }
+            },
+            "pool_params": "0xc0ffee",
+            "max_iterations": 10_000,
+            "max_results": 1,
+            "seed": 1234
+        }))
+        .unwrap();
+
+        let mining_result = mine_eulerswap_salt(&config);
+        assert_eq!(mining_result.results.len(), 1);
+
+        let result = &mining_result.results[0];
+        assert_eq!(
+            V4HookPermissions::from_address(result.computed_address).to_names(),
+            vec!["beforeSwap", "afterSwap"],
+            "The custom release's permissions must be mined for"
+        );
+        assert_eq!(
+            result.computed_address,
+            eulerswap_address_for_version(
+                address!("2222222222222222222222222222222222222222"),
+                address!("3333333333333333333333333333333333333333"),
+                &config.version,
+                &config.pool_params.abi_encode(),
+                result.salt,
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "vault0 and vault1 are both")]
+    fn test_mine_eulerswap_rejects_invalid_params() {
+        let vault = address!("797dd80692c3b2dadabce8e30c07fde5307d48a9");
+        let config = EulerSwapConfig {
+            factory: Address::ZERO,
+            eulerswap_impl: Address::ZERO,
+            version: Default::default(),
+            pool_params: EulerSwapParams {
+                vault0: vault,
+                vault1: vault,
+                euler_account: Address::ZERO,
+                equilibrium_reserve0: U112::from(1),
+                equilibrium_reserve1: U112::from(1),
+                price_x: U256::from(1),
+                price_y: U256::from(1),
+                concentration_x: U256::ZERO,
+                concentration_y: U256::ZERO,
+                fee: U256::ZERO,
+                protocol_fee: U256::ZERO,
+                protocol_fee_recipient: Address::ZERO,
+            }
+            .into(),
+            max_iterations: 1,
+            max_results: 1,
+            seed: None,
+        };
+
+        mine_eulerswap_salt(&config);
+    }
+
+    #[test]
+    fn test_try_mine_eulerswap_returns_layout_mismatch() {
+        let config: EulerSwapConfig = serde_json::from_value(serde_json::json!({
+            "factory": "0x2222222222222222222222222222222222222222",
+            "eulerswap_impl": "0x3333333333333333333333333333333333333333",
+            "pool_params": "0xc0ffee",
+            "max_iterations": 1,
+            "max_results": 1,
+            "seed": null
+        }))
+        .unwrap();
+
+        assert_eq!(
+            try_mine_eulerswap_salt(&config).unwrap_err(),
+            EulerSwapError::ParamsLayoutMismatch
+        );
+    }
+}
@@ -1,6 +1,13 @@
+pub mod calldata;
 pub mod compute;
 pub mod config;
 pub mod miner;
 
-pub use config::{EulerSwapConfig, EulerSwapResult};
-pub use miner::mine_eulerswap_salt;
+pub use calldata::{EulerSwapInitialState, deploy_pool_batch_calldata, deploy_pool_calldata};
+pub use compute::{creation_code_eulerswap, eulerswap_address, eulerswap_address_for_version};
+pub use config::{
+    EULERSWAP_V1_HOOK_PERMISSIONS, EulerSwapConfig, EulerSwapDeployment, EulerSwapError,
+    EulerSwapMatch, EulerSwapParams, EulerSwapPoolParams, EulerSwapResult, EulerSwapVersion,
+    MAX_CONCENTRATION, MAX_FEE, MAX_PRICE,
+};
+pub use miner::{mine_eulerswap_salt, try_mine_eulerswap_salt};
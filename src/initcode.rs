@@ -0,0 +1,389 @@
+use alloy_dyn_abi::{DynSolType, DynSolValue, JsonAbiExt, Specifier};
+use alloy_json_abi::{JsonAbi, Param};
+use alloy_primitives::{B256, Bytes, hex, keccak256};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Errors raised while deriving init code from bytecode and constructor arguments.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum InitCodeError {
+    #[error("invalid bytecode hex: {0}")]
+    InvalidHex(String),
+    #[error("bytecode contains unlinked library placeholders")]
+    UnlinkedLibraries,
+    #[error("invalid contract artifact: {0}")]
+    InvalidArtifact(String),
+    #[error("constructor arguments as values require a contract artifact with an ABI")]
+    MissingAbi,
+    #[error("expected {expected} constructor arguments, got {actual}")]
+    ArgumentCount { expected: usize, actual: usize },
+    #[error("invalid constructor argument `{name}`: {reason}")]
+    InvalidArgument { name: String, reason: String },
+}
+
+/// The creation bytecode and ABI of a compiled contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractArtifact {
+    pub abi: JsonAbi,
+    pub bytecode: Bytes,
+}
+
+impl ContractArtifact {
+    /// Parses a Foundry (`bytecode.object`) or Hardhat (`bytecode`) artifact JSON.
+    pub fn from_json(json: &str) -> Result<Self, InitCodeError> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum ArtifactBytecode {
+            Foundry { object: String },
+            Hardhat(String),
+        }
+
+        #[derive(Deserialize)]
+        struct Artifact {
+            abi: JsonAbi,
+            bytecode: ArtifactBytecode,
+        }
+
+        let artifact: Artifact = serde_json::from_str(json)
+            .map_err(|e| InitCodeError::InvalidArtifact(e.to_string()))?;
+        let bytecode = match artifact.bytecode {
+            ArtifactBytecode::Foundry { object } => object,
+            ArtifactBytecode::Hardhat(bytecode) => bytecode,
+        };
+
+        Ok(Self {
+            abi: artifact.abi,
+            bytecode: parse_bytecode(&bytecode)?,
+        })
+    }
+
+    /// ABI-encodes JSON constructor arguments against the artifact's constructor.
+    ///
+    /// Leaf values may be JSON strings, numbers or booleans and are parsed like Solidity
+    /// literals, so large integers should be passed as strings. Arrays map to JSON arrays, and
+    /// tuples to JSON arrays or objects keyed by component name.
+    pub fn encode_constructor_args(&self, args: &[Value]) -> Result<Vec<u8>, InitCodeError> {
+        let inputs = self
+            .abi
+            .constructor
+            .as_ref()
+            .map(|constructor| constructor.inputs.as_slice())
+            .unwrap_or_default();
+        if inputs.len() != args.len() {
+            return Err(InitCodeError::ArgumentCount {
+                expected: inputs.len(),
+                actual: args.len(),
+            });
+        }
+        let Some(constructor) = &self.abi.constructor else {
+            return Ok(Vec::new());
+        };
+
+        let values = inputs
+            .iter()
+            .zip(args)
+            .map(|(param, arg)| {
+                let ty = param
+                    .resolve()
+                    .map_err(|e| InitCodeError::InvalidArgument {
+                        name: param.name.clone(),
+                        reason: e.to_string(),
+                    })?;
+                json_to_sol_value(&ty, &param.components, arg).map_err(|reason| {
+                    InitCodeError::InvalidArgument {
+                        name: param.name.clone(),
+                        reason,
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        constructor
+            .abi_encode_input(&values)
+            .map_err(|e| InitCodeError::InvalidArgument {
+                name: String::new(),
+                reason: e.to_string(),
+            })
+    }
+}
+
+/// Constructor arguments, either as JSON values mapped through the ABI or pre-encoded bytes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConstructorArgs {
+    Values(Vec<Value>),
+    Encoded(Bytes),
+}
+
+impl Default for ConstructorArgs {
+    fn default() -> Self {
+        Self::Values(Vec::new())
+    }
+}
+
+/// The init code of a contract deployment and its hash, as used by CREATE2.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InitCode {
+    /// The creation bytecode followed by the ABI-encoded constructor arguments.
+    pub init_code: Bytes,
+    /// `keccak256(init_code)`.
+    pub init_code_hash: B256,
+}
+
+impl InitCode {
+    /// Appends ABI-encoded constructor arguments to the creation bytecode.
+    pub fn new(bytecode: &[u8], encoded_args: &[u8]) -> Self {
+        let mut init_code = Vec::with_capacity(bytecode.len() + encoded_args.len());
+        init_code.extend_from_slice(bytecode);
+        init_code.extend_from_slice(encoded_args);
+
+        Self {
+            init_code_hash: keccak256(&init_code),
+            init_code: init_code.into(),
+        }
+    }
+}
+
+/// Parses creation bytecode from hex, with or without the `0x` prefix.
+pub fn parse_bytecode(bytecode: &str) -> Result<Bytes, InitCodeError> {
+    let bytecode = bytecode.trim();
+    if bytecode.contains("__") {
+        return Err(InitCodeError::UnlinkedLibraries);
+    }
+    hex::decode(bytecode)
+        .map(Bytes::from)
+        .map_err(|e| InitCodeError::InvalidHex(e.to_string()))
+}
+
+/// Computes the init code from hex bytecode or artifact JSON plus constructor arguments.
+///
+/// ## Arguments
+/// * `bytecode` - Creation bytecode as hex, or a Foundry/Hardhat artifact JSON.
+/// * `args` - The constructor arguments. JSON values require an artifact to map them via its ABI.
+///
+/// ## Returns
+/// The `InitCode` with its hash, ready for `Create2Config::init_code_hash`.
+pub fn compute_init_code(
+    bytecode: &str,
+    args: &ConstructorArgs,
+) -> Result<InitCode, InitCodeError> {
+    if bytecode.trim_start().starts_with('{') {
+        let artifact = ContractArtifact::from_json(bytecode)?;
+        let encoded_args = match args {
+            ConstructorArgs::Values(values) => artifact.encode_constructor_args(values)?,
+            ConstructorArgs::Encoded(encoded) => encoded.to_vec(),
+        };
+        return Ok(InitCode::new(&artifact.bytecode, &encoded_args));
+    }
+
+    let bytecode = parse_bytecode(bytecode)?;
+    match args {
+        ConstructorArgs::Values(values) if !values.is_empty() => Err(InitCodeError::MissingAbi),
+        ConstructorArgs::Values(_) => Ok(InitCode::new(&bytecode, &[])),
+        ConstructorArgs::Encoded(encoded) => Ok(InitCode::new(&bytecode, encoded)),
+    }
+}
+
+fn json_to_sol_value(
+    ty: &DynSolType,
+    components: &[Param],
+    value: &Value,
+) -> Result<DynSolValue, String> {
+    match (ty, value) {
+        (DynSolType::Array(inner), Value::Array(items)) => items
+            .iter()
+            .map(|item| json_to_sol_value(inner, components, item))
+            .collect::<Result<_, _>>()
+            .map(DynSolValue::Array),
+        (DynSolType::FixedArray(inner, len), Value::Array(items)) => {
+            if items.len() != *len {
+                return Err(format!("expected {len} elements, got {}", items.len()));
+            }
+            items
+                .iter()
+                .map(|item| json_to_sol_value(inner, components, item))
+                .collect::<Result<_, _>>()
+                .map(DynSolValue::FixedArray)
+        }
+        (DynSolType::Tuple(types), Value::Array(items)) => {
+            if items.len() != types.len() {
+                return Err(format!(
+                    "expected {} tuple components, got {}",
+                    types.len(),
+                    items.len()
+                ));
+            }
+            types
+                .iter()
+                .zip(items)
+                .enumerate()
+                .map(|(i, (ty, item))| {
+                    let nested = components
+                        .get(i)
+                        .map(|c| c.components.as_slice())
+                        .unwrap_or_default();
+                    json_to_sol_value(ty, nested, item)
+                })
+                .collect::<Result<_, _>>()
+                .map(DynSolValue::Tuple)
+        }
+        (DynSolType::Tuple(types), Value::Object(fields)) => {
+            if components.len() != types.len() {
+                return Err("tuple components are unnamed in the ABI".to_string());
+            }
+            types
+                .iter()
+                .zip(components)
+                .map(|(ty, component)| {
+                    let item = fields
+                        .get(&component.name)
+                        .ok_or_else(|| format!("missing tuple component `{}`", component.name))?;
+                    json_to_sol_value(ty, &component.components, item)
+                })
+                .collect::<Result<_, _>>()
+                .map(DynSolValue::Tuple)
+        }
+        (_, Value::String(s)) => ty.coerce_str(s).map_err(|e| e.to_string()),
+        (_, Value::Number(n)) => ty.coerce_str(&n.to_string()).map_err(|e| e.to_string()),
+        (_, Value::Bool(b)) => ty.coerce_str(&b.to_string()).map_err(|e| e.to_string()),
+        _ => Err(format!("cannot convert {value} to {ty}")),
+    }
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_compute_init_code(bytecode: &str, args: JsValue) -> Result<JsValue, JsValue> {
+    let args: Option<ConstructorArgs> =
+        from_value(args).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let init_code = compute_init_code(bytecode, &args.unwrap_or_default())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&init_code).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{U256, address};
+    use alloy_sol_types::{SolValue, sol};
+    use serde_json::json;
+
+    const BYTECODE: &str = "0x6080604052348015600f57600080fd5b50";
+
+    fn artifact_json(foundry: bool) -> String {
+        let abi = json!([{
+            "type": "constructor",
+            "stateMutability": "nonpayable",
+            "inputs": [
+                { "name": "owner", "type": "address", "internalType": "address" },
+                { "name": "supply", "type": "uint256", "internalType": "uint256" },
+                { "name": "tags", "type": "string[]", "internalType": "string[]" },
+                {
+                    "name": "config",
+                    "type": "tuple",
+                    "internalType": "struct Config",
+                    "components": [
+                        { "name": "enabled", "type": "bool", "internalType": "bool" },
+                        { "name": "fee", "type": "uint24", "internalType": "uint24" }
+                    ]
+                }
+            ]
+        }]);
+        let bytecode = if foundry {
+            json!({ "object": BYTECODE, "sourceMap": "", "linkReferences": {} })
+        } else {
+            json!(BYTECODE)
+        };
+        json!({ "abi": abi, "bytecode": bytecode }).to_string()
+    }
+
+    #[test]
+    fn test_compute_init_code_from_artifacts() {
+        sol! {
+            struct Config {
+                bool enabled;
+                uint24 fee;
+            }
+        }
+        let owner = address!("DeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF");
+        let expected_args = (
+            owner,
+            U256::from(10).pow(U256::from(24)),
+            vec!["a".to_string(), "b".to_string()],
+            Config {
+                enabled: true,
+                fee: alloy_primitives::aliases::U24::from(3000),
+            },
+        )
+            .abi_encode_params();
+        let expected = InitCode::new(&parse_bytecode(BYTECODE).unwrap(), &expected_args);
+
+        let args = ConstructorArgs::Values(vec![
+            json!(owner.to_string()),
+            json!("1000000000000000000000000"),
+            json!(["a", "b"]),
+            json!({ "enabled": true, "fee": 3000 }),
+        ]);
+        for foundry in [true, false] {
+            let init_code = compute_init_code(&artifact_json(foundry), &args).unwrap();
+            assert_eq!(
+                init_code,
+                expected,
+                "Init code mismatch for {} artifact",
+                if foundry { "Foundry" } else { "Hardhat" }
+            );
+        }
+    }
+
+    #[test]
+    fn test_compute_init_code_from_hex() {
+        let encoded = U256::from(42).abi_encode();
+        let init_code =
+            compute_init_code(BYTECODE, &ConstructorArgs::Encoded(encoded.clone().into())).unwrap();
+
+        let mut expected = hex::decode(BYTECODE).unwrap();
+        expected.extend_from_slice(&encoded);
+        assert_eq!(init_code.init_code, Bytes::from(expected.clone()));
+        assert_eq!(init_code.init_code_hash, keccak256(&expected));
+    }
+
+    #[test]
+    fn test_compute_init_code_errors() {
+        assert_eq!(
+            compute_init_code(BYTECODE, &ConstructorArgs::Values(vec![json!(1)])),
+            Err(InitCodeError::MissingAbi)
+        );
+        assert_eq!(
+            compute_init_code(
+                "0x6080__$1234567890abcdef1234567890abcdef12$__",
+                &ConstructorArgs::default()
+            ),
+            Err(InitCodeError::UnlinkedLibraries)
+        );
+        assert_eq!(
+            compute_init_code(&artifact_json(true), &ConstructorArgs::default()),
+            Err(InitCodeError::ArgumentCount {
+                expected: 4,
+                actual: 0
+            })
+        );
+        assert!(matches!(
+            compute_init_code(
+                &artifact_json(true),
+                &ConstructorArgs::Values(vec![
+                    json!("not an address"),
+                    json!(1),
+                    json!([]),
+                    json!([true, 1])
+                ])
+            ),
+            Err(InitCodeError::InvalidArgument { name, .. }) if name == "owner"
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+use alloy_primitives::{Address, B256, keccak256};
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::{AffinePoint, ProjectivePoint, Scalar, U256};
+
+/// Reduces 32 bytes into a secp256k1 private key, returning `None` for the zero scalar.
+pub fn private_key_from_bytes(bytes: B256) -> Option<Scalar> {
+    let scalar = <Scalar as Reduce<U256>>::reduce_bytes(&bytes.0.into());
+    (!bool::from(scalar.is_zero())).then_some(scalar)
+}
+
+/// Computes the Ethereum address of a secp256k1 public key.
+pub fn public_key_address(public_key: &AffinePoint) -> Address {
+    let encoded = public_key.to_encoded_point(false);
+    // Skip the 0x04 uncompressed point tag.
+    let hash = keccak256(&encoded.as_bytes()[1..]);
+    Address::from_slice(&hash[12..])
+}
+
+/// Computes the Ethereum address controlled by a private key.
+pub fn private_key_address(private_key: &Scalar) -> Address {
+    let public_key = (ProjectivePoint::GENERATOR * private_key).to_affine();
+    public_key_address(&public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    #[test]
+    fn test_private_key_address() {
+        let cases = [
+            (
+                b256!("0000000000000000000000000000000000000000000000000000000000000001"),
+                address!("7e5f4552091a69125d5dfcb7b8c2659029395bdf"),
+            ),
+            (
+                b256!("0000000000000000000000000000000000000000000000000000000000000002"),
+                address!("2b5ad5c4795c026514f8317c7a215e218dccd6cf"),
+            ),
+            (
+                b256!("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"),
+                address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+            ),
+        ];
+
+        for (key, expected_address) in cases {
+            let private_key = private_key_from_bytes(key).unwrap();
+            assert_eq!(
+                private_key_address(&private_key),
+                expected_address,
+                "Address derivation mismatch for private key {}",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn test_private_key_from_zero_bytes() {
+        assert!(private_key_from_bytes(B256::ZERO).is_none());
+    }
+}
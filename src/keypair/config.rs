@@ -0,0 +1,77 @@
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_serde;
+
+/// Errors raised when a keypair mining configuration is unusable.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum KeypairError {
+    #[error("entropy must not reduce to the zero private key")]
+    ZeroEntropy,
+}
+
+/// Configuration for the EOA keypair mining process.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct KeypairConfig {
+    /// 32 bytes of secret entropy from a cryptographically secure RNG, used as the first
+    /// candidate private key. Anyone who knows it can recover every key mined from it.
+    pub entropy: B256,
+    /// The maximum number of attempts before giving up.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_results: u64,
+}
+
+impl fmt::Debug for KeypairConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeypairConfig")
+            .field("entropy", &"<redacted>")
+            .field("max_iterations", &self.max_iterations)
+            .field("max_results", &self.max_results)
+            .finish()
+    }
+}
+
+/// A single successful match from a keypair mining operation.
+///
+/// Keys found in the same run are consecutive scalars, so only hand out one of them per owner.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeypairMatch {
+    /// The 32-byte big-endian private key, importable by any Ethereum wallet.
+    pub private_key: B256,
+    /// The address controlled by the private key.
+    pub computed_address: Address,
+}
+
+impl fmt::Debug for KeypairMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeypairMatch")
+            .field("private_key", &"<redacted>")
+            .field("computed_address", &self.computed_address)
+            .finish()
+    }
+}
+
+/// Result structure that includes keypair matches and total iterations.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KeypairResult {
+    /// The found matches.
+    pub results: Vec<KeypairMatch>,
+    /// Total number of iterations performed.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(serialize_with = "crate::wasm_serde::serialize_usize")
+    )]
+    pub total_iterations: usize,
+}
@@ -0,0 +1,231 @@
+use crate::keypair::compute::{private_key_from_bytes, public_key_address};
+use crate::keypair::config::{KeypairConfig, KeypairError, KeypairMatch, KeypairResult};
+use crate::pattern::{contains_predicate, prefix_predicate, suffix_predicate};
+use alloy_primitives::{Address, B256};
+use k256::elliptic_curve::group::Curve;
+use k256::{AffinePoint, ProjectivePoint, Scalar};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Number of public keys converted to affine coordinates with a single field inversion.
+const BATCH_SIZE: usize = 256;
+
+/// Mines for a private key whose address satisfies the given predicate.
+///
+/// Starting from the entropy in the config, each iteration adds the generator point to the
+/// previous public key instead of performing a full scalar multiplication. Points are
+/// normalized to affine coordinates in batches of `BATCH_SIZE`, sharing one inversion.
+///
+/// ## Arguments
+/// * `config` - A `KeypairConfig` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `KeypairResult` containing the found keys/addresses and total iterations.
+pub fn mine_keypair(
+    config: &KeypairConfig,
+    predicate: &dyn Fn(Address) -> bool,
+) -> Result<KeypairResult, KeypairError> {
+    let mut private_key =
+        private_key_from_bytes(config.entropy).ok_or(KeypairError::ZeroEntropy)?;
+    let mut public_key = ProjectivePoint::GENERATOR * private_key;
+    let mut points = [ProjectivePoint::IDENTITY; BATCH_SIZE];
+    let mut affine_points = [AffinePoint::IDENTITY; BATCH_SIZE];
+    let mut results = Vec::new();
+
+    let mut total_iterations = 0;
+
+    'mining: while (total_iterations as u64) < config.max_iterations {
+        let remaining = config.max_iterations - total_iterations as u64;
+        let batch = BATCH_SIZE.min(remaining.try_into().unwrap_or(BATCH_SIZE));
+
+        for point in &mut points[..batch] {
+            *point = public_key;
+            public_key += ProjectivePoint::GENERATOR;
+        }
+        ProjectivePoint::batch_normalize(&points[..batch], &mut affine_points[..batch]);
+
+        for affine_point in &affine_points[..batch] {
+            total_iterations += 1;
+
+            // Skips the point at infinity should the scalar ever wrap around to zero.
+            if !bool::from(private_key.is_zero()) {
+                let computed_address = public_key_address(affine_point);
+
+                if predicate(computed_address) {
+                    results.push(KeypairMatch {
+                        private_key: B256::from(<[u8; 32]>::from(private_key.to_bytes())),
+                        computed_address,
+                    });
+
+                    if results.len() >= config.max_results as usize {
+                        break 'mining;
+                    }
+                }
+            }
+
+            private_key += Scalar::ONE;
+        }
+    }
+
+    Ok(KeypairResult {
+        results,
+        total_iterations,
+    })
+}
+
+/// Mines for a private key whose address has a specific prefix.
+pub fn mine_keypair_with_prefix(
+    config: &KeypairConfig,
+    prefix: &[u8],
+) -> Result<KeypairResult, KeypairError> {
+    mine_keypair(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a private key whose address has a specific suffix.
+pub fn mine_keypair_with_suffix(
+    config: &KeypairConfig,
+    suffix: &[u8],
+) -> Result<KeypairResult, KeypairError> {
+    mine_keypair(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a private key whose address contains a specific byte sequence.
+pub fn mine_keypair_with_contains(
+    config: &KeypairConfig,
+    contains: &[u8],
+) -> Result<KeypairResult, KeypairError> {
+    mine_keypair(config, &contains_predicate(contains))
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_keypair_with_prefix(config: JsValue, prefix: &[u8]) -> Result<JsValue, JsValue> {
+    let config: KeypairConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result =
+        mine_keypair_with_prefix(&config, prefix).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_keypair_with_suffix(config: JsValue, suffix: &[u8]) -> Result<JsValue, JsValue> {
+    let config: KeypairConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result =
+        mine_keypair_with_suffix(&config, suffix).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_keypair_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: KeypairConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_keypair_with_contains(&config, contains)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keypair::compute::private_key_address;
+    use alloy_primitives::b256;
+
+    const ENTROPY: B256 = b256!("9f4a1c3e5b7d2f608192a3b4c5d6e7f80112233445566778899aabbccddeeff0");
+
+    #[test]
+    fn test_mine_keypair_for_prefix() {
+        let config = KeypairConfig {
+            entropy: ENTROPY,
+            max_iterations: 100_000,
+            max_results: 2,
+        };
+
+        let prefix = &[0xee];
+        let mining_result = mine_keypair_with_prefix(&config, prefix).unwrap();
+        assert_eq!(
+            mining_result.results.len(),
+            2,
+            "Failed to find two keys for prefix 0xee within {} iterations",
+            config.max_iterations
+        );
+
+        for result in &mining_result.results {
+            assert!(
+                result.computed_address.starts_with(prefix),
+                "Address should start with prefix 0xee"
+            );
+
+            let private_key = private_key_from_bytes(result.private_key).unwrap();
+            assert_eq!(
+                private_key_address(&private_key),
+                result.computed_address,
+                "Incrementally derived address must match the private key"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mine_keypair_steps_across_batches() {
+        let config = KeypairConfig {
+            entropy: ENTROPY,
+            max_iterations: BATCH_SIZE as u64 * 2 + 3,
+            max_results: u64::MAX,
+        };
+
+        let mining_result = mine_keypair(&config, &|_| true).unwrap();
+        assert_eq!(mining_result.total_iterations, BATCH_SIZE * 2 + 3);
+        assert_eq!(mining_result.results.len(), BATCH_SIZE * 2 + 3);
+
+        let start = private_key_from_bytes(ENTROPY).unwrap();
+        for (i, result) in mining_result.results.iter().enumerate() {
+            let private_key = start + Scalar::from(i as u64);
+            assert_eq!(
+                result.private_key,
+                B256::from(<[u8; 32]>::from(private_key.to_bytes())),
+                "Key {i} must be entropy + {i}"
+            );
+            assert_eq!(
+                result.computed_address,
+                private_key_address(&private_key),
+                "Key {i} must map to the address of (k + {i})·G"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mine_keypair_rejects_zero_entropy() {
+        let config = KeypairConfig {
+            entropy: B256::ZERO,
+            max_iterations: 1,
+            max_results: 1,
+        };
+
+        assert_eq!(
+            mine_keypair(&config, &|_| true).unwrap_err(),
+            KeypairError::ZeroEntropy
+        );
+    }
+
+    #[test]
+    fn test_keypair_match_debug_redacts_private_key() {
+        let result = KeypairMatch {
+            private_key: ENTROPY,
+            computed_address: Address::ZERO,
+        };
+
+        assert!(!format!("{result:?}").contains("9f4a1c3e"));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod compute;
+pub mod config;
+pub mod miner;
+
+pub use compute::{private_key_address, private_key_from_bytes, public_key_address};
+pub use config::{KeypairConfig, KeypairError, KeypairMatch, KeypairResult};
+pub use miner::{
+    mine_keypair, mine_keypair_with_contains, mine_keypair_with_prefix, mine_keypair_with_suffix,
+};
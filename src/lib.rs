@@ -1,6 +1,16 @@
+pub mod clones;
 pub mod createx;
+pub mod erc4337;
+pub mod erc6551;
 pub mod eulerswap;
+pub mod initcode;
+pub mod keypair;
+pub mod meta_proxy;
+pub mod pattern;
+pub mod safe;
+pub mod univ4;
 pub mod univ4_hook;
+pub mod zksync;
 
 #[cfg(target_arch = "wasm32")]
 pub mod wasm_serde;
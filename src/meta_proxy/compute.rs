@@ -0,0 +1,77 @@
+use crate::createx::create2_address;
+use alloy_primitives::{Address, B256, U256, keccak256};
+
+/// The meta-proxy creation code preceding the implementation address.
+pub const BYTECODE_HEAD: &[u8] = &[
+    0x60, 0x0b, 0x38, 0x03, 0x80, 0x60, 0x0b, 0x3d, 0x39, 0x3d, 0xf3, 0x36, 0x3d, 0x3d, 0x37, 0x3d,
+    0x3d, 0x3d, 0x3d, 0x60, 0x36, 0x80, 0x38, 0x03, 0x80, 0x91, 0x36, 0x39, 0x36, 0x01, 0x3d, 0x73,
+];
+/// The meta-proxy creation code between the implementation address and the metadata.
+pub const BYTECODE_TAIL: &[u8] = &[
+    0x5a, 0xf4, 0x3d, 0x3d, 0x93, 0x80, 0x3e, 0x60, 0x34, 0x57, 0xfd, 0x5b, 0xf3,
+];
+
+/// Computes the ERC-3448 meta-proxy creation code for a target contract and its metadata.
+///
+/// The metadata is appended verbatim. The reference `MetaProxyFactory` additionally appends
+/// the metadata length, see [`metadata_with_length`].
+///
+/// See [`ERC-3448`](https://eips.ethereum.org/EIPS/eip-3448).
+pub fn creation_code_meta_proxy(target_contract: Address, metadata: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(BYTECODE_HEAD);
+    bytes.extend_from_slice(target_contract.as_slice());
+    bytes.extend_from_slice(BYTECODE_TAIL);
+    bytes.extend_from_slice(metadata);
+
+    bytes
+}
+
+/// Appends the metadata length as a `uint256`, as the reference `MetaProxyFactory` does.
+pub fn metadata_with_length(metadata: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(metadata.len() + 32);
+    bytes.extend_from_slice(metadata);
+    bytes.extend_from_slice(&U256::from(metadata.len()).to_be_bytes::<32>());
+
+    bytes
+}
+
+/// Computes the address of a meta-proxy deployed with CREATE2.
+pub fn meta_proxy_address(
+    deployer: Address,
+    implementation: Address,
+    metadata: &[u8],
+    salt: B256,
+) -> Address {
+    let init_code_hash = keccak256(creation_code_meta_proxy(implementation, metadata));
+
+    create2_address(deployer, salt, init_code_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex};
+
+    #[test]
+    fn test_creation_code_meta_proxy() {
+        let implementation = address!("bebebebebebebebebebebebebebebebebebebebe");
+        let metadata = hex!("c0ffee");
+
+        let expected = hex!(
+            "600b380380600b3d393df3363d3d373d3d3d3d60368038038091363936013d73bebebebebebebebebebebebebebebebebebebebe5af43d3d93803e603457fd5bf3c0ffee"
+        );
+        assert_eq!(
+            creation_code_meta_proxy(implementation, &metadata),
+            expected
+        );
+
+        let with_length = metadata_with_length(&metadata);
+        assert_eq!(&with_length[..3], &metadata, "Metadata must come first");
+        assert_eq!(
+            U256::from_be_slice(&with_length[3..]),
+            U256::from(3),
+            "Length must be appended as a uint256"
+        );
+    }
+}
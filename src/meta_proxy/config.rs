@@ -0,0 +1,41 @@
+use crate::createx::{Create2Match, Create2Result};
+use alloy_primitives::{Address, Bytes};
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_serde;
+
+/// Configuration for the ERC-3448 meta-proxy mining process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetaProxyConfig {
+    /// The factory deploying the meta-proxy with CREATE2.
+    pub deployer: Address,
+    /// The implementation the meta-proxy delegates to.
+    pub implementation: Address,
+    /// The ABI-encoded metadata appended to the proxy's code.
+    pub metadata: Bytes,
+    /// The maximum number of attempts before giving up.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_results: u64,
+    /// Seed for the random number generator.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_option_u128")
+    )]
+    pub seed: Option<u128>,
+}
+
+/// A single successful match from a meta-proxy mining operation.
+pub type MetaProxyMatch = Create2Match;
+
+/// Result structure that includes meta-proxy matches and total iterations.
+pub type MetaProxyResult = Create2Result;
@@ -0,0 +1,143 @@
+use crate::createx::{Create2Config, mine_create2_salt};
+use crate::meta_proxy::compute::creation_code_meta_proxy;
+use crate::meta_proxy::config::{MetaProxyConfig, MetaProxyResult};
+use crate::pattern::{contains_predicate, prefix_predicate, suffix_predicate};
+use alloy_primitives::{Address, keccak256};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Mines for a salt that produces a meta-proxy address satisfying the given predicate.
+///
+/// ## Arguments
+/// * `config` - A `MetaProxyConfig` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `MetaProxyResult` containing the found salts/addresses and total iterations.
+pub fn mine_meta_proxy_salt(
+    config: &MetaProxyConfig,
+    predicate: &dyn Fn(Address) -> bool,
+) -> MetaProxyResult {
+    let creation_code = creation_code_meta_proxy(config.implementation, &config.metadata);
+    let create2_config = Create2Config {
+        deployer: config.deployer,
+        init_code_hash: keccak256(creation_code),
+        max_iterations: config.max_iterations,
+        max_results: config.max_results,
+        salt_template: None,
+        seed: config.seed,
+    };
+
+    mine_create2_salt(&create2_config, predicate)
+}
+
+/// Mines for a salt that produces a meta-proxy address with a specific prefix.
+pub fn mine_meta_proxy_salt_with_prefix(
+    config: &MetaProxyConfig,
+    prefix: &[u8],
+) -> MetaProxyResult {
+    mine_meta_proxy_salt(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a salt that produces a meta-proxy address with a specific suffix.
+pub fn mine_meta_proxy_salt_with_suffix(
+    config: &MetaProxyConfig,
+    suffix: &[u8],
+) -> MetaProxyResult {
+    mine_meta_proxy_salt(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a salt that produces a meta-proxy address containing a specific byte sequence.
+pub fn mine_meta_proxy_salt_with_contains(
+    config: &MetaProxyConfig,
+    contains: &[u8],
+) -> MetaProxyResult {
+    mine_meta_proxy_salt(config, &contains_predicate(contains))
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_meta_proxy_salt_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: MetaProxyConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_meta_proxy_salt_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_meta_proxy_salt_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: MetaProxyConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_meta_proxy_salt_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_meta_proxy_salt_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: MetaProxyConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_meta_proxy_salt_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta_proxy::compute::meta_proxy_address;
+    use alloy_primitives::{address, bytes};
+
+    #[test]
+    fn test_mine_meta_proxy_for_contains() {
+        let config = MetaProxyConfig {
+            deployer: address!("5fbdb2315678afecb367f032d93f642f64180aa3"),
+            implementation: address!("bebebebebebebebebebebebebebebebebebebebe"),
+            metadata: bytes!("000000000000000000000000000000000000000000000000000000000000002a"),
+            max_iterations: 1_000_000,
+            max_results: 1,
+            seed: Some(1234),
+        };
+
+        let contains = &[0x3f, 0x48];
+        let mining_result = mine_meta_proxy_salt_with_contains(&config, contains);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find address containing 0x3f48 within {} iterations",
+            config.max_iterations
+        );
+
+        let result = &mining_result.results[0];
+        assert!(
+            result
+                .computed_address
+                .windows(contains.len())
+                .any(|window| window == contains)
+        );
+        assert_eq!(
+            result.computed_address,
+            meta_proxy_address(
+                config.deployer,
+                config.implementation,
+                &config.metadata,
+                result.salt
+            ),
+            "Address computation mismatch"
+        );
+    }
+}
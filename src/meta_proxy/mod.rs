@@ -0,0 +1,10 @@
+pub mod compute;
+pub mod config;
+pub mod miner;
+
+pub use compute::{creation_code_meta_proxy, meta_proxy_address, metadata_with_length};
+pub use config::{MetaProxyConfig, MetaProxyMatch, MetaProxyResult};
+pub use miner::{
+    mine_meta_proxy_salt, mine_meta_proxy_salt_with_contains, mine_meta_proxy_salt_with_prefix,
+    mine_meta_proxy_salt_with_suffix,
+};
@@ -0,0 +1,42 @@
+use alloy_primitives::Address;
+
+/// Returns a predicate matching addresses that start with `prefix`.
+pub fn prefix_predicate(prefix: &[u8]) -> impl Fn(Address) -> bool + '_ {
+    if prefix.len() > 20 {
+        panic!("Prefix cannot be longer than 20 bytes");
+    }
+    move |addr: Address| addr.starts_with(prefix)
+}
+
+/// Returns a predicate matching addresses that end with `suffix`.
+pub fn suffix_predicate(suffix: &[u8]) -> impl Fn(Address) -> bool + '_ {
+    if suffix.len() > 20 {
+        panic!("Suffix cannot be longer than 20 bytes");
+    }
+    move |addr: Address| addr.ends_with(suffix)
+}
+
+/// Returns a predicate matching addresses that contain `contains` anywhere.
+pub fn contains_predicate(contains: &[u8]) -> impl Fn(Address) -> bool + '_ {
+    if contains.len() > 20 {
+        panic!("Contained sequence cannot be longer than 20 bytes");
+    }
+    move |addr: Address| {
+        addr.windows(contains.len())
+            .any(|window| window == contains)
+    }
+}
+
+/// Estimates the expected number of attempts to match a prefix or suffix of `len` bytes.
+pub fn anchored_difficulty(len: usize) -> f64 {
+    256f64.powi(len as i32)
+}
+
+/// Estimates the expected number of attempts to match a `len`-byte sequence anywhere in an
+/// address, treating the `21 - len` possible positions as independent.
+pub fn contains_difficulty(len: usize) -> f64 {
+    if len == 0 {
+        return 1.0;
+    }
+    anchored_difficulty(len) / (21 - len.min(20)) as f64
+}
@@ -0,0 +1,53 @@
+use crate::createx::create2_address;
+use alloy_primitives::{Address, B256, U256, hex, keccak256};
+
+/// The `SafeProxy` creation code returned by `SafeProxyFactory.proxyCreationCode()` in v1.4.1.
+pub const PROXY_CREATION_CODE_V1_4_1: &[u8] = &hex!(
+    "608060405234801561001057600080fd5b506040516101e63803806101e68339818101604052602081101561003357600080fd5b8101908080519060200190929190505050600073ffffffffffffffffffffffffffffffffffffffff168173ffffffffffffffffffffffffffffffffffffffff1614156100ca576040517f08c379a00000000000000000000000000000000000000000000000000000000081526004018080602001828103825260228152602001806101c46022913960400191505060405180910390fd5b806000806101000a81548173ffffffffffffffffffffffffffffffffffffffff021916908373ffffffffffffffffffffffffffffffffffffffff1602179055505060ab806101196000396000f3fe608060405273ffffffffffffffffffffffffffffffffffffffff600054167fa619486e0000000000000000000000000000000000000000000000000000000060003514156050578060005260206000f35b3660008037600080366000845af43d6000803e60008114156070573d6000fd5b3d6000f3fea264697066735822122003d1488ee65e08fa41e58e888a9865554c535f2c77126a82cb4c0f917f31441364736f6c63430007060033496e76616c69642073696e676c65746f6e20616464726573732070726f7669646564"
+);
+
+/// Computes the CREATE2 salt used by `SafeProxyFactory.createProxyWithNonce`.
+pub fn safe_salt(initializer: &[u8], salt_nonce: U256) -> B256 {
+    safe_salt_from_initializer_hash(keccak256(initializer), salt_nonce)
+}
+
+/// Computes the `createProxyWithNonce` salt from an already hashed initializer.
+///
+/// The initializer is fixed while mining, so callers hash it once and only
+/// hash `initializer_hash ‖ saltNonce` per attempt.
+pub fn safe_salt_from_initializer_hash(initializer_hash: B256, salt_nonce: U256) -> B256 {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(initializer_hash.as_slice());
+    bytes[32..].copy_from_slice(&salt_nonce.to_be_bytes::<32>());
+
+    keccak256(bytes)
+}
+
+/// Computes the init code hash of a Safe proxy pointing to `singleton`.
+pub fn safe_proxy_init_code_hash(proxy_creation_code: &[u8], singleton: Address) -> B256 {
+    let mut init_code = Vec::with_capacity(proxy_creation_code.len() + 32);
+    init_code.extend_from_slice(proxy_creation_code);
+    init_code.extend_from_slice(singleton.into_word().as_slice());
+
+    keccak256(init_code)
+}
+
+/// Computes the address of a Safe proxy deployed with `createProxyWithNonce`.
+///
+/// ## Arguments
+/// * `factory` - The `SafeProxyFactory` address.
+/// * `proxy_init_code_hash` - The hash returned by `safe_proxy_init_code_hash`.
+/// * `initializer` - The calldata of the `setup` call.
+/// * `salt_nonce` - The `saltNonce` passed to the factory.
+pub fn safe_proxy_address(
+    factory: Address,
+    proxy_init_code_hash: B256,
+    initializer: &[u8],
+    salt_nonce: U256,
+) -> Address {
+    create2_address(
+        factory,
+        safe_salt(initializer, salt_nonce),
+        proxy_init_code_hash,
+    )
+}
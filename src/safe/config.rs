@@ -0,0 +1,298 @@
+use crate::safe::compute::{PROXY_CREATION_CODE_V1_4_1, safe_proxy_init_code_hash};
+use alloy_primitives::{Address, B256, Bytes, U256, address};
+use alloy_sol_types::{SolCall, sol};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_serde;
+
+sol! {
+    /// See [`Safe`](https://github.com/safe-global/safe-smart-account/blob/v1.4.1/contracts/Safe.sol).
+    interface ISafe {
+        function setup(address[] calldata _owners, uint256 _threshold, address to, bytes calldata data, address fallbackHandler, address paymentToken, uint256 payment, address payable paymentReceiver) external;
+    }
+
+    /// See [`SafeProxyFactory`](https://github.com/safe-global/safe-smart-account/blob/v1.4.1/contracts/proxies/SafeProxyFactory.sol).
+    interface ISafeProxyFactory {
+        function createProxyWithNonce(address _singleton, bytes memory initializer, uint256 saltNonce) external returns (address proxy);
+    }
+}
+
+/// The sentinel used by the Safe's owner linked list, which cannot be an owner.
+const SENTINEL_OWNERS: Address = address!("0000000000000000000000000000000000000001");
+
+/// Errors raised when a Safe setup would revert in `OwnerManager.setupOwners`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SafeError {
+    #[error("a Safe needs at least one owner")]
+    NoOwners,
+    #[error("threshold {threshold} must be between 1 and the number of owners ({owners})")]
+    InvalidThreshold { threshold: u64, owners: usize },
+    #[error("{0} cannot be a Safe owner")]
+    InvalidOwner(Address),
+    #[error("owner {0} is listed more than once")]
+    DuplicateOwner(Address),
+}
+
+/// The contracts a Safe proxy is deployed with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafeDeployment {
+    /// The `SafeProxyFactory` address.
+    pub factory: Address,
+    /// The Safe singleton the proxy delegates to.
+    pub singleton: Address,
+    /// The factory's `proxyCreationCode()`.
+    pub proxy_creation_code: Bytes,
+}
+
+/// The Safe release to deploy, selecting the factory, singleton and proxy creation code.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SafeVersion {
+    /// The canonical v1.4.1 `Safe` singleton.
+    #[default]
+    V1_4_1,
+    /// The canonical v1.4.1 `SafeL2` singleton, which emits events for indexers.
+    V1_4_1L2,
+    /// Any other release or deployment, e.g. v1.3.0 or a chain-specific factory.
+    Custom(SafeDeployment),
+}
+
+impl SafeVersion {
+    /// Returns the contracts of this release.
+    pub fn deployment(&self) -> SafeDeployment {
+        const FACTORY_V1_4_1: Address = address!("4e1dcf7ad4e460cfd30791ccc4f9c8a4f820ec67");
+        match self {
+            Self::V1_4_1 => SafeDeployment {
+                factory: FACTORY_V1_4_1,
+                singleton: address!("41675c099f32341bf84bfc5382af534df5c7461a"),
+                proxy_creation_code: Bytes::from_static(PROXY_CREATION_CODE_V1_4_1),
+            },
+            Self::V1_4_1L2 => SafeDeployment {
+                factory: FACTORY_V1_4_1,
+                singleton: address!("29fcb43b46531bca003ddc8fcb67ffe91900c762"),
+                proxy_creation_code: Bytes::from_static(PROXY_CREATION_CODE_V1_4_1),
+            },
+            Self::Custom(deployment) => deployment.clone(),
+        }
+    }
+}
+
+/// The arguments of the `Safe.setup` call used as the proxy initializer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafeSetup {
+    /// The Safe owners.
+    pub owners: Vec<Address>,
+    /// The number of confirmations required for a transaction.
+    pub threshold: u64,
+    /// The fallback handler, e.g. `CompatibilityFallbackHandler`.
+    #[serde(default)]
+    pub fallback_handler: Address,
+    /// An optional contract to delegatecall during setup.
+    #[serde(default)]
+    pub to: Address,
+    /// The calldata for the optional delegatecall.
+    #[serde(default)]
+    pub data: Bytes,
+    /// The token used to pay for the deployment, with the zero address for ETH.
+    #[serde(default)]
+    pub payment_token: Address,
+    /// The payment for the deployment.
+    #[serde(default)]
+    pub payment: U256,
+    /// The receiver of the payment, with the zero address for `tx.origin`.
+    #[serde(default)]
+    pub payment_receiver: Address,
+}
+
+impl SafeSetup {
+    /// Creates a setup with the given owners and threshold and no optional calls or payment.
+    pub fn new(owners: Vec<Address>, threshold: u64, fallback_handler: Address) -> Self {
+        Self {
+            owners,
+            threshold,
+            fallback_handler,
+            to: Address::ZERO,
+            data: Bytes::new(),
+            payment_token: Address::ZERO,
+            payment: U256::ZERO,
+            payment_receiver: Address::ZERO,
+        }
+    }
+
+    /// Checks the owners and threshold as `OwnerManager.setupOwners` does.
+    pub fn validate(&self) -> Result<(), SafeError> {
+        if self.owners.is_empty() {
+            return Err(SafeError::NoOwners);
+        }
+        if self.threshold == 0 || self.threshold as usize > self.owners.len() {
+            return Err(SafeError::InvalidThreshold {
+                threshold: self.threshold,
+                owners: self.owners.len(),
+            });
+        }
+        for (i, owner) in self.owners.iter().enumerate() {
+            if owner.is_zero() || *owner == SENTINEL_OWNERS {
+                return Err(SafeError::InvalidOwner(*owner));
+            }
+            if self.owners[..i].contains(owner) {
+                return Err(SafeError::DuplicateOwner(*owner));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the `setup` calldata passed to the factory as `initializer`.
+    pub fn initializer(&self) -> Bytes {
+        ISafe::setupCall {
+            _owners: self.owners.clone(),
+            _threshold: U256::from(self.threshold),
+            to: self.to,
+            data: self.data.clone(),
+            fallbackHandler: self.fallback_handler,
+            paymentToken: self.payment_token,
+            payment: self.payment,
+            paymentReceiver: self.payment_receiver,
+        }
+        .abi_encode()
+        .into()
+    }
+}
+
+/// Configuration for the Safe `saltNonce` mining process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SafeConfig {
+    /// The Safe release to deploy.
+    #[serde(default)]
+    pub version: SafeVersion,
+    /// The `setup` call initializing the proxy.
+    pub setup: SafeSetup,
+    /// The maximum number of attempts before giving up.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_results: u64,
+    /// Seed for the random number generator.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_option_u128")
+    )]
+    pub seed: Option<u128>,
+}
+
+impl SafeConfig {
+    /// Returns the factory and the proxy init code hash of the configured release.
+    pub fn factory_and_init_code_hash(&self) -> (Address, B256) {
+        let deployment = self.version.deployment();
+        let init_code_hash =
+            safe_proxy_init_code_hash(&deployment.proxy_creation_code, deployment.singleton);
+
+        (deployment.factory, init_code_hash)
+    }
+
+    /// Encodes the `createProxyWithNonce` calldata deploying the Safe with a mined `saltNonce`.
+    pub fn create_proxy_calldata(&self, salt_nonce: U256) -> Bytes {
+        ISafeProxyFactory::createProxyWithNonceCall {
+            _singleton: self.version.deployment().singleton,
+            initializer: self.setup.initializer(),
+            saltNonce: salt_nonce,
+        }
+        .abi_encode()
+        .into()
+    }
+}
+
+/// A single successful match from a Safe mining operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafeMatch {
+    /// The `saltNonce` to pass to `createProxyWithNonce`.
+    pub salt_nonce: U256,
+    /// The address of the Safe proxy.
+    pub computed_address: Address,
+}
+
+/// Result structure that includes Safe matches and total iterations.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SafeResult {
+    /// The found matches.
+    pub results: Vec<SafeMatch>,
+    /// Total number of iterations performed.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(serialize_with = "crate::wasm_serde::serialize_usize")
+    )]
+    pub total_iterations: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setup_validation() {
+        let alice = address!("1111111111111111111111111111111111111111");
+        let bob = address!("2222222222222222222222222222222222222222");
+
+        assert_eq!(
+            SafeSetup::new(vec![alice, bob], 2, Address::ZERO).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            SafeSetup::new(vec![], 1, Address::ZERO).validate(),
+            Err(SafeError::NoOwners)
+        );
+        assert_eq!(
+            SafeSetup::new(vec![alice], 2, Address::ZERO).validate(),
+            Err(SafeError::InvalidThreshold {
+                threshold: 2,
+                owners: 1
+            })
+        );
+        assert_eq!(
+            SafeSetup::new(vec![alice, SENTINEL_OWNERS], 1, Address::ZERO).validate(),
+            Err(SafeError::InvalidOwner(SENTINEL_OWNERS))
+        );
+        assert_eq!(
+            SafeSetup::new(vec![alice, bob, alice], 1, Address::ZERO).validate(),
+            Err(SafeError::DuplicateOwner(alice))
+        );
+    }
+
+    #[test]
+    fn test_version_names() {
+        assert_eq!(
+            serde_json::to_string(&SafeVersion::V1_4_1).unwrap(),
+            "\"v1_4_1\""
+        );
+        assert_eq!(
+            serde_json::from_str::<SafeVersion>("\"v1_4_1_l2\"").unwrap(),
+            SafeVersion::V1_4_1L2
+        );
+    }
+
+    #[test]
+    fn test_initializer_encodes_setup() {
+        let owner = address!("1111111111111111111111111111111111111111");
+        let handler = address!("fd0732dc9e303f09fcef3a7388ad10a83459ec99");
+        let initializer = SafeSetup::new(vec![owner], 1, handler).initializer();
+
+        let decoded = ISafe::setupCall::abi_decode(&initializer).unwrap();
+        assert_eq!(decoded._owners, vec![owner]);
+        assert_eq!(decoded._threshold, U256::from(1));
+        assert_eq!(decoded.fallbackHandler, handler);
+        assert_eq!(
+            decoded.to,
+            Address::ZERO,
+            "No setup delegatecall by default"
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use crate::createx::{SaltTemplate, create2_address};
+use crate::pattern::{contains_predicate, prefix_predicate, suffix_predicate};
+use crate::safe::compute::safe_salt_from_initializer_hash;
+use crate::safe::config::{SafeConfig, SafeMatch, SafeResult};
+use alloy_primitives::{Address, U256, keccak256};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+const DEFAULT_SEED: u128 = 1337;
+
+/// Mines for a `saltNonce` that produces a Safe proxy address satisfying the given predicate.
+///
+/// ## Arguments
+/// * `config` - A `SafeConfig` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `SafeResult` containing the found salt nonces/addresses and total iterations.
+pub fn mine_safe_salt_nonce(
+    config: &SafeConfig,
+    predicate: &dyn Fn(Address) -> bool,
+) -> SafeResult {
+    if let Err(e) = config.setup.validate() {
+        panic!("Invalid config: {e}");
+    }
+    let (factory, init_code_hash) = config.factory_and_init_code_hash();
+    let initializer_hash = keccak256(config.setup.initializer());
+    let mut cursor = SaltTemplate::fully_mined().cursor(config.seed.unwrap_or(DEFAULT_SEED));
+    let mut results = Vec::new();
+
+    let mut total_iterations = 0;
+
+    for i in 0..config.max_iterations {
+        total_iterations = (i + 1) as usize;
+        let salt_nonce = U256::from_be_bytes(cursor.next_salt().0);
+
+        let salt = safe_salt_from_initializer_hash(initializer_hash, salt_nonce);
+        let computed_address = create2_address(factory, salt, init_code_hash);
+
+        if predicate(computed_address) {
+            results.push(SafeMatch {
+                salt_nonce,
+                computed_address,
+            });
+
+            if results.len() >= config.max_results as usize {
+                break;
+            }
+        }
+    }
+
+    SafeResult {
+        results,
+        total_iterations,
+    }
+}
+
+/// Mines for a `saltNonce` that produces a Safe address with a specific prefix.
+pub fn mine_safe_salt_nonce_with_prefix(config: &SafeConfig, prefix: &[u8]) -> SafeResult {
+    mine_safe_salt_nonce(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a `saltNonce` that produces a Safe address with a specific suffix.
+pub fn mine_safe_salt_nonce_with_suffix(config: &SafeConfig, suffix: &[u8]) -> SafeResult {
+    mine_safe_salt_nonce(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a `saltNonce` that produces a Safe address containing a specific byte sequence.
+pub fn mine_safe_salt_nonce_with_contains(config: &SafeConfig, contains: &[u8]) -> SafeResult {
+    mine_safe_salt_nonce(config, &contains_predicate(contains))
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_safe_salt_nonce_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: SafeConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .setup
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_safe_salt_nonce_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_safe_salt_nonce_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: SafeConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .setup
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_safe_salt_nonce_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_safe_salt_nonce_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: SafeConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .setup
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_safe_salt_nonce_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_safe_create_proxy_calldata(
+    config: JsValue,
+    salt_nonce: JsValue,
+) -> Result<JsValue, JsValue> {
+    let config: SafeConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let salt_nonce: U256 = from_value(salt_nonce).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let calldata = config.create_proxy_calldata(salt_nonce);
+    to_value(&calldata).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::safe::compute::{safe_proxy_address, safe_proxy_init_code_hash};
+    use crate::safe::config::{SafeSetup, SafeVersion};
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_mine_safe_for_prefix() {
+        let config = SafeConfig {
+            version: SafeVersion::V1_4_1,
+            setup: SafeSetup::new(
+                vec![address!("1111111111111111111111111111111111111111")],
+                1,
+                address!("fd0732dc9e303f09fcef3a7388ad10a83459ec99"),
+            ),
+            max_iterations: 1_000_000,
+            max_results: 1,
+            seed: Some(1234),
+        };
+
+        let prefix = &[0x5a, 0xfe];
+        let mining_result = mine_safe_salt_nonce_with_prefix(&config, prefix);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find address starting with 0x5afe within {} iterations",
+            config.max_iterations
+        );
+
+        let result = &mining_result.results[0];
+        assert!(result.computed_address.starts_with(prefix));
+
+        let (factory, init_code_hash) = config.factory_and_init_code_hash();
+        assert_eq!(
+            result.computed_address,
+            safe_proxy_address(
+                factory,
+                init_code_hash,
+                &config.setup.initializer(),
+                result.salt_nonce
+            ),
+            "Address computation mismatch"
+        );
+    }
+
+    #[test]
+    fn test_safe_v1_4_1_proxy_vectors() {
+        // Canonical v1.4.1 `SafeProxyFactory`, `Safe` singleton and
+        // `CompatibilityFallbackHandler`; expected addresses from safe-rs
+        // `predict_safe_address` with the factory's `proxyCreationCode()`.
+        let handler = address!("fd0732Dc9E303f09fCEf3a7388Ad10A83459Ec99");
+        let vectors = [
+            (
+                vec![address!("1111111111111111111111111111111111111111")],
+                1,
+                U256::ZERO,
+                address!("Cc61Ba0546004bbC11b5d5bfdAfe9A0C98DD6841"),
+            ),
+            (
+                vec![
+                    address!("1111111111111111111111111111111111111111"),
+                    address!("2222222222222222222222222222222222222222"),
+                ],
+                2,
+                U256::from(42),
+                address!("643e66E2081C7aACae644E434a50beDFBdF8C26b"),
+            ),
+        ];
+
+        for (owners, threshold, salt_nonce, expected) in vectors {
+            let config = SafeConfig {
+                version: SafeVersion::V1_4_1,
+                setup: SafeSetup::new(owners, threshold, handler),
+                max_iterations: 1,
+                max_results: 1,
+                seed: None,
+            };
+            let deployment = config.version.deployment();
+            assert_eq!(
+                deployment.factory,
+                address!("4e1DCf7AD4e460CfD30791CCC4F9c8a4f820ec67")
+            );
+            assert_eq!(
+                deployment.singleton,
+                address!("41675C099F32341bf84BFc5382aF534df5C7461a")
+            );
+
+            let init_code_hash =
+                safe_proxy_init_code_hash(&deployment.proxy_creation_code, deployment.singleton);
+            assert_eq!(
+                safe_proxy_address(
+                    deployment.factory,
+                    init_code_hash,
+                    &config.setup.initializer(),
+                    salt_nonce
+                ),
+                expected,
+                "Safe proxy address mismatch for saltNonce {salt_nonce}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid config")]
+    fn test_mine_safe_rejects_invalid_setup() {
+        let config = SafeConfig {
+            version: SafeVersion::default(),
+            setup: SafeSetup::new(vec![], 1, Address::ZERO),
+            max_iterations: 1,
+            max_results: 1,
+            seed: None,
+        };
+
+        mine_safe_salt_nonce(&config, &|_| true);
+    }
+}
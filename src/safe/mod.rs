@@ -0,0 +1,15 @@
+pub mod compute;
+pub mod config;
+pub mod miner;
+
+pub use compute::{
+    PROXY_CREATION_CODE_V1_4_1, safe_proxy_address, safe_proxy_init_code_hash, safe_salt,
+    safe_salt_from_initializer_hash,
+};
+pub use config::{
+    SafeConfig, SafeDeployment, SafeError, SafeMatch, SafeResult, SafeSetup, SafeVersion,
+};
+pub use miner::{
+    mine_safe_salt_nonce, mine_safe_salt_nonce_with_contains, mine_safe_salt_nonce_with_prefix,
+    mine_safe_salt_nonce_with_suffix,
+};
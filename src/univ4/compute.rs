@@ -0,0 +1,32 @@
+use alloy_primitives::Address;
+
+/// Sorts two currencies into `(currency0, currency1)` order as required by the `PoolManager`.
+///
+/// The native currency is the zero address, so it always sorts first.
+pub fn sort_currencies(currency_a: Address, currency_b: Address) -> (Address, Address) {
+    if currency_a < currency_b {
+        (currency_a, currency_b)
+    } else {
+        (currency_b, currency_a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_sort_currencies() {
+        let usdc = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        let weth = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+
+        assert_eq!(sort_currencies(weth, usdc), (usdc, weth));
+        assert_eq!(sort_currencies(usdc, weth), (usdc, weth));
+        assert_eq!(
+            sort_currencies(usdc, Address::ZERO),
+            (Address::ZERO, usdc),
+            "The native currency should always be currency0"
+        );
+    }
+}
@@ -0,0 +1,399 @@
+use crate::univ4::compute::sort_currencies;
+use crate::univ4_hook::{V4HookError, validate_hook_address};
+use alloy_primitives::{
+    Address, B256, Bytes, U160,
+    aliases::{I24, U24},
+    keccak256,
+};
+use alloy_sol_types::{SolCall, SolValue, sol};
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+sol! {
+    /// See [`IPoolManager`](https://github.com/Uniswap/v4-core/blob/main/src/interfaces/IPoolManager.sol).
+    interface IPoolManager {
+        struct PoolKey {
+            address currency0;
+            address currency1;
+            uint24 fee;
+            int24 tickSpacing;
+            address hooks;
+        }
+
+        function initialize(PoolKey memory key, uint160 sqrtPriceX96) external returns (int24 tick);
+    }
+}
+
+/// The fee value marking a pool whose LP fee is set by its hook (`LPFeeLibrary.DYNAMIC_FEE_FLAG`).
+pub const DYNAMIC_FEE_FLAG: u32 = 0x800000;
+
+/// The maximum static LP fee in hundredths of a bip (`LPFeeLibrary.MAX_LP_FEE`), i.e. 100%.
+pub const MAX_LP_FEE: u32 = 1_000_000;
+
+/// The smallest tick spacing accepted by the `PoolManager` (`TickMath.MIN_TICK_SPACING`).
+pub const MIN_TICK_SPACING: i32 = 1;
+
+/// The largest tick spacing accepted by the `PoolManager` (`TickMath.MAX_TICK_SPACING`).
+pub const MAX_TICK_SPACING: i32 = i16::MAX as i32;
+
+/// Errors raised when `PoolManager.initialize` would revert on a pool key.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PoolKeyError {
+    #[error("currency0 {currency0} must sort strictly before currency1 {currency1}")]
+    CurrenciesOutOfOrder {
+        currency0: Address,
+        currency1: Address,
+    },
+    #[error("fee {0} exceeds the maximum LP fee of {MAX_LP_FEE} and is not the dynamic fee flag")]
+    InvalidFee(u32),
+    #[error("tick spacing {0} must be between {MIN_TICK_SPACING} and {MAX_TICK_SPACING}")]
+    InvalidTickSpacing(i32),
+    #[error("dynamic-fee pools need a hook to set the fee")]
+    DynamicFeeWithoutHook,
+    #[error("hook address {hooks} is not valid for the pool: {source}")]
+    InvalidHook {
+        hooks: Address,
+        #[source]
+        source: V4HookError,
+    },
+}
+
+/// A Uniswap v4 `PoolKey` identifying a pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolKey {
+    /// The lower currency of the pool, sorted numerically.
+    pub currency0: Address,
+    /// The higher currency of the pool, sorted numerically.
+    pub currency1: Address,
+    /// The LP fee in hundredths of a bip, or `DYNAMIC_FEE_FLAG`.
+    pub fee: u32,
+    /// The tick spacing of the pool.
+    pub tick_spacing: i32,
+    /// The hook contract of the pool, or the zero address.
+    pub hooks: Address,
+}
+
+impl PoolKey {
+    /// Creates a pool key, sorting the two currencies.
+    pub fn new(
+        currency_a: Address,
+        currency_b: Address,
+        fee: u32,
+        tick_spacing: i32,
+        hooks: Address,
+    ) -> Self {
+        let (currency0, currency1) = sort_currencies(currency_a, currency_b);
+        Self {
+            currency0,
+            currency1,
+            fee,
+            tick_spacing,
+            hooks,
+        }
+    }
+
+    /// Validates the key against the checks in `PoolManager.initialize`, including
+    /// `Hooks.isValidHookAddress` for the pool's fee.
+    pub fn validate(&self) -> Result<(), PoolKeyError> {
+        if self.currency0 >= self.currency1 {
+            return Err(PoolKeyError::CurrenciesOutOfOrder {
+                currency0: self.currency0,
+                currency1: self.currency1,
+            });
+        }
+        if self.fee > MAX_LP_FEE && self.fee != DYNAMIC_FEE_FLAG {
+            return Err(PoolKeyError::InvalidFee(self.fee));
+        }
+        if !(MIN_TICK_SPACING..=MAX_TICK_SPACING).contains(&self.tick_spacing) {
+            return Err(PoolKeyError::InvalidTickSpacing(self.tick_spacing));
+        }
+
+        let dynamic_fee = self.fee == DYNAMIC_FEE_FLAG;
+        if self.hooks.is_zero() {
+            if dynamic_fee {
+                return Err(PoolKeyError::DynamicFeeWithoutHook);
+            }
+        } else {
+            validate_hook_address(self.hooks, None, dynamic_fee).map_err(|source| {
+                PoolKeyError::InvalidHook {
+                    hooks: self.hooks,
+                    source,
+                }
+            })?;
+        }
+        Ok(())
+    }
+
+    /// ABI-encodes the validated key, as `abi.encode(key)` does.
+    pub fn abi_encode(&self) -> Result<Bytes, PoolKeyError> {
+        Ok(self.to_sol()?.abi_encode().into())
+    }
+
+    /// Computes the `PoolId` of the validated key, as `PoolIdLibrary.toId` does.
+    pub fn pool_id(&self) -> Result<B256, PoolKeyError> {
+        Ok(keccak256(self.abi_encode()?))
+    }
+
+    /// Encodes `PoolManager.initialize(key, sqrtPriceX96)` calldata for the validated key.
+    ///
+    /// ## Arguments
+    /// * `sqrt_price_x96` - The initial price of the pool as a Q64.96 square root.
+    ///
+    /// ## Returns
+    /// The calldata as `Bytes`, or why `initialize` would revert on the key.
+    pub fn initialize_calldata(&self, sqrt_price_x96: U160) -> Result<Bytes, PoolKeyError> {
+        Ok(IPoolManager::initializeCall {
+            key: self.to_sol()?,
+            sqrtPriceX96: sqrt_price_x96,
+        }
+        .abi_encode()
+        .into())
+    }
+
+    /// Converts the key to its Solidity form, validating it first so the fee and tick spacing
+    /// fit their `uint24`/`int24` fields.
+    fn to_sol(self) -> Result<IPoolManager::PoolKey, PoolKeyError> {
+        self.validate()?;
+
+        Ok(IPoolManager::PoolKey {
+            currency0: self.currency0,
+            currency1: self.currency1,
+            fee: U24::from(self.fee),
+            tickSpacing: I24::unchecked_from(self.tick_spacing),
+            hooks: self.hooks,
+        })
+    }
+}
+
+/// Sorts two currencies into `[currency0, currency1]`.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_sort_currencies(currency_a: JsValue, currency_b: JsValue) -> Result<JsValue, JsValue> {
+    let currency_a: Address =
+        from_value(currency_a).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let currency_b: Address =
+        from_value(currency_b).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    to_value(&sort_currencies(currency_a, currency_b))
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Computes the `PoolId` of a validated pool key.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_v4_pool_id(key: JsValue) -> Result<JsValue, JsValue> {
+    let key: PoolKey = from_value(key).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let pool_id = key
+        .pool_id()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&pool_id).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// ABI-encodes a validated pool key.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_v4_encode_pool_key(key: JsValue) -> Result<JsValue, JsValue> {
+    let key: PoolKey = from_value(key).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let encoded = key
+        .abi_encode()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&encoded).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Encodes `PoolManager.initialize` calldata for a validated pool key.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_v4_initialize_calldata(
+    key: JsValue,
+    sqrt_price_x96: JsValue,
+) -> Result<JsValue, JsValue> {
+    let key: PoolKey = from_value(key).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let sqrt_price_x96: U160 =
+        from_value(sqrt_price_x96).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let calldata = key
+        .initialize_calldata(sqrt_price_x96)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&calldata).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{U256, address, hex, keccak256, uint};
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const HOOKS: Address = address!("00000000000000000000000000000000000020c0");
+
+    #[test]
+    fn test_pool_key_encoding_and_id() {
+        let key = PoolKey::new(USDC, Address::ZERO, 3000, 60, HOOKS);
+        assert_eq!(key.currency0, Address::ZERO);
+        assert_eq!(key.currency1, USDC);
+        assert_eq!(key.validate(), Ok(()));
+
+        let encoded = key.abi_encode().unwrap();
+        let expected = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000000"
+            "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+            "0000000000000000000000000000000000000000000000000000000000000bb8"
+            "000000000000000000000000000000000000000000000000000000000000003c"
+            "00000000000000000000000000000000000000000000000000000000000020c0"
+        );
+        assert_eq!(encoded.as_ref(), expected.as_slice());
+        assert_eq!(key.pool_id(), Ok(keccak256(expected)));
+    }
+
+    #[test]
+    fn test_initialize_calldata() {
+        let key = PoolKey::new(USDC, Address::ZERO, DYNAMIC_FEE_FLAG, 1, HOOKS);
+        let sqrt_price_x96 = uint!(79228162514264337593543950336_U160);
+
+        let calldata = key.initialize_calldata(sqrt_price_x96).unwrap();
+        assert_eq!(
+            calldata[..4],
+            hex!("6276cbbe"),
+            "initialize((address,address,uint24,int24,address),uint160) selector"
+        );
+        assert_eq!(&calldata[4..164], key.abi_encode().unwrap().as_ref());
+        assert_eq!(
+            calldata[164..],
+            U256::from(sqrt_price_x96).to_be_bytes::<32>(),
+            "The price is the last static argument"
+        );
+    }
+
+    #[test]
+    fn test_pool_key_validation() {
+        let key = PoolKey::new(USDC, Address::ZERO, 3000, 60, HOOKS);
+
+        assert_eq!(
+            PoolKey {
+                currency0: USDC,
+                currency1: USDC,
+                ..key
+            }
+            .validate(),
+            Err(PoolKeyError::CurrenciesOutOfOrder {
+                currency0: USDC,
+                currency1: USDC,
+            })
+        );
+        assert_eq!(
+            PoolKey {
+                fee: MAX_LP_FEE + 1,
+                ..key
+            }
+            .validate(),
+            Err(PoolKeyError::InvalidFee(MAX_LP_FEE + 1))
+        );
+        assert_eq!(
+            PoolKey {
+                tick_spacing: 0,
+                ..key
+            }
+            .validate(),
+            Err(PoolKeyError::InvalidTickSpacing(0))
+        );
+        assert_eq!(
+            PoolKey {
+                tick_spacing: MAX_TICK_SPACING + 1,
+                ..key
+            }
+            .validate(),
+            Err(PoolKeyError::InvalidTickSpacing(MAX_TICK_SPACING + 1))
+        );
+    }
+
+    #[test]
+    fn test_pool_key_encoding_rejects_out_of_range_fields() {
+        let key = PoolKey::new(USDC, Address::ZERO, 3000, 60, HOOKS);
+        // Would truncate to fee 3000 as a `uint24`.
+        let wide_fee = PoolKey {
+            fee: 0x1000bb8,
+            ..key
+        };
+        assert_eq!(wide_fee.pool_id(), Err(PoolKeyError::InvalidFee(0x1000bb8)));
+        assert_eq!(
+            wide_fee.abi_encode(),
+            Err(PoolKeyError::InvalidFee(0x1000bb8))
+        );
+
+        let wide_spacing = PoolKey {
+            tick_spacing: 1 << 23,
+            ..key
+        };
+        assert_eq!(
+            wide_spacing.initialize_calldata(U160::from(1)),
+            Err(PoolKeyError::InvalidTickSpacing(1 << 23))
+        );
+    }
+
+    #[test]
+    fn test_pool_key_validates_hook_address() {
+        let key = PoolKey::new(USDC, Address::ZERO, 3000, 60, Address::ZERO);
+        assert_eq!(
+            key.validate(),
+            Ok(()),
+            "Hookless static-fee pools are valid"
+        );
+
+        assert_eq!(
+            PoolKey {
+                fee: DYNAMIC_FEE_FLAG,
+                ..key
+            }
+            .validate(),
+            Err(PoolKeyError::DynamicFeeWithoutHook)
+        );
+
+        // BEFORE_SWAP_RETURNS_DELTA without BEFORE_SWAP.
+        let hooks = address!("0000000000000000000000000000000000000008");
+        assert!(matches!(
+            PoolKey { hooks, ..key }.validate(),
+            Err(PoolKeyError::InvalidHook {
+                source: V4HookError::ReturnDeltaWithoutParent { .. },
+                ..
+            })
+        ));
+
+        // A hook without flags only sets the fee of dynamic-fee pools.
+        let hooks = address!("1000000000000000000000000000000000000000");
+        assert_eq!(
+            PoolKey { hooks, ..key }.validate(),
+            Err(PoolKeyError::InvalidHook {
+                hooks,
+                source: V4HookError::NoPermissions,
+            })
+        );
+        assert_eq!(
+            PoolKey {
+                hooks,
+                fee: DYNAMIC_FEE_FLAG,
+                ..key
+            }
+            .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_pool_key_serde() {
+        let key: PoolKey = serde_json::from_str(
+            r#"{"currency0":"0x0000000000000000000000000000000000000000","currency1":"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48","fee":500,"tickSpacing":10,"hooks":"0x0000000000000000000000000000000000000000"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            key,
+            PoolKey::new(USDC, Address::ZERO, 500, 10, Address::ZERO)
+        );
+    }
+}
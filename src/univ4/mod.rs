@@ -0,0 +1,7 @@
+pub mod compute;
+pub mod config;
+
+pub use compute::sort_currencies;
+pub use config::{
+    DYNAMIC_FEE_FLAG, MAX_LP_FEE, MAX_TICK_SPACING, MIN_TICK_SPACING, PoolKey, PoolKeyError,
+};
@@ -1,6 +1,17 @@
-use crate::createx::config::{Create2Match, Create2Result};
-use alloy_primitives::{Address, B256};
-use serde::{Deserialize, Serialize};
+use crate::createx::config::{
+    ConfigError, Create2Match, Create2Result, Create3Config, Create3Match, Create3Result,
+    Create3Scheme, CrossChainProtection, SenderProtection, resolve_legacy_protection,
+};
+use crate::createx::template::SaltTemplate;
+use alloy_primitives::{Address, B256, ChainId};
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+use std::{fmt, marker::PhantomData, str::FromStr};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
 use crate::wasm_serde;
@@ -24,8 +35,242 @@ pub const AFTER_SWAP_RETURNS_DELTA_FLAG: u16 = 1 << 2;
 pub const AFTER_ADD_LIQUIDITY_RETURNS_DELTA_FLAG: u16 = 1 << 1;
 pub const AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA_FLAG: u16 = 1 << 0;
 
+/// The `Hooks.Permissions` field name of every flag.
+pub const PERMISSION_NAMES: [(&str, u16); 14] = [
+    ("beforeInitialize", BEFORE_INITIALIZE_FLAG),
+    ("afterInitialize", AFTER_INITIALIZE_FLAG),
+    ("beforeAddLiquidity", BEFORE_ADD_LIQUIDITY_FLAG),
+    ("afterAddLiquidity", AFTER_ADD_LIQUIDITY_FLAG),
+    ("beforeRemoveLiquidity", BEFORE_REMOVE_LIQUIDITY_FLAG),
+    ("afterRemoveLiquidity", AFTER_REMOVE_LIQUIDITY_FLAG),
+    ("beforeSwap", BEFORE_SWAP_FLAG),
+    ("afterSwap", AFTER_SWAP_FLAG),
+    ("beforeDonate", BEFORE_DONATE_FLAG),
+    ("afterDonate", AFTER_DONATE_FLAG),
+    ("beforeSwapReturnDelta", BEFORE_SWAP_RETURNS_DELTA_FLAG),
+    ("afterSwapReturnDelta", AFTER_SWAP_RETURNS_DELTA_FLAG),
+    (
+        "afterAddLiquidityReturnDelta",
+        AFTER_ADD_LIQUIDITY_RETURNS_DELTA_FLAG,
+    ),
+    (
+        "afterRemoveLiquidityReturnDelta",
+        AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA_FLAG,
+    ),
+];
+
+/// The snake_case field name of every flag, in the order of `PERMISSION_NAMES`.
+const SNAKE_CASE_PERMISSION_NAMES: [&str; 14] = [
+    "before_initialize",
+    "after_initialize",
+    "before_add_liquidity",
+    "after_add_liquidity",
+    "before_remove_liquidity",
+    "after_remove_liquidity",
+    "before_swap",
+    "after_swap",
+    "before_donate",
+    "after_donate",
+    "before_swap_return_delta",
+    "after_swap_return_delta",
+    "after_add_liquidity_return_delta",
+    "after_remove_liquidity_return_delta",
+];
+
+/// Each return-delta flag paired with the action flag it requires.
+const RETURN_DELTA_PARENTS: [(u16, u16); 4] = [
+    (BEFORE_SWAP_RETURNS_DELTA_FLAG, BEFORE_SWAP_FLAG),
+    (AFTER_SWAP_RETURNS_DELTA_FLAG, AFTER_SWAP_FLAG),
+    (
+        AFTER_ADD_LIQUIDITY_RETURNS_DELTA_FLAG,
+        AFTER_ADD_LIQUIDITY_FLAG,
+    ),
+    (
+        AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA_FLAG,
+        AFTER_REMOVE_LIQUIDITY_FLAG,
+    ),
+];
+
+/// Returns the `Hooks.Permissions` field name of a single flag.
+fn permission_name(flag: u16) -> &'static str {
+    PERMISSION_NAMES
+        .iter()
+        .find(|(_, f)| *f == flag)
+        .map(|(name, _)| *name)
+        .expect("flag must be a single hook permission")
+}
+
+/// Returns the names of all permissions set in `flags`.
+fn permission_names(flags: u16) -> Vec<&'static str> {
+    PERMISSION_NAMES
+        .iter()
+        .filter(|(_, flag)| flags & flag != 0)
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// Errors raised when hook permissions would be rejected by Uniswap v4's `Hooks` library.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum V4HookError {
+    #[error("{permission} requires {parent}, otherwise the PoolManager rejects the hook")]
+    ReturnDeltaWithoutParent {
+        permission: &'static str,
+        parent: &'static str,
+    },
+    #[error("hook address is missing {missing:?} and has unexpected {unexpected:?}")]
+    PermissionMismatch {
+        missing: Vec<&'static str>,
+        unexpected: Vec<&'static str>,
+    },
+    #[error("a hook without any permission flag is only accepted by dynamic-fee pools")]
+    NoPermissions,
+    #[error("unknown hook permission `{0}`")]
+    UnknownPermission(String),
+    #[error("hook permission `{0}` is given more than once")]
+    DuplicatePermission(&'static str),
+    #[error("the `Hooks.Permissions` struct is missing `{0}`")]
+    MissingPermission(&'static str),
+    #[error(
+        "`{0}` is not a permission requirement, expected a boolean, \"required\", \"forbidden\" or \"any\""
+    )]
+    InvalidRequirement(String),
+    #[error("`{0}` is not a hex bitmask of the 14 hook flags")]
+    InvalidBitmask(String),
+    #[error(transparent)]
+    Create3(#[from] ConfigError),
+}
+
+/// Hook permissions as written in a config, before they are checked.
+enum PermissionsRepr<T> {
+    /// A hex bitmask such as `"0x00C0"`.
+    Bitmask(String),
+    /// A numeric bitmask.
+    Flags(u64),
+    /// A list of `Hooks.Permissions` names.
+    Names(Vec<String>),
+    /// An object keyed by `Hooks.Permissions` names or their snake_case form.
+    Fields(Vec<(String, T)>),
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for PermissionsRepr<T> {
+    /// Dispatches on the shape of the input, so each format reports its own `V4HookError`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ReprVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for ReprVisitor<T> {
+            type Value = PermissionsRepr<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a hex bitmask, a list of `Hooks.Permissions` names or an object")
+            }
+
+            fn visit_str<E: de::Error>(self, bitmask: &str) -> Result<Self::Value, E> {
+                Ok(PermissionsRepr::Bitmask(bitmask.to_string()))
+            }
+
+            fn visit_u64<E: de::Error>(self, flags: u64) -> Result<Self::Value, E> {
+                Ok(PermissionsRepr::Flags(flags))
+            }
+
+            fn visit_i64<E: de::Error>(self, flags: i64) -> Result<Self::Value, E> {
+                u64::try_from(flags)
+                    .map(PermissionsRepr::Flags)
+                    .map_err(|_| E::custom(V4HookError::InvalidBitmask(flags.to_string())))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut names = Vec::new();
+                while let Some(name) = seq.next_element()? {
+                    names.push(name);
+                }
+                Ok(PermissionsRepr::Names(names))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut fields = Vec::new();
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+                Ok(PermissionsRepr::Fields(fields))
+            }
+        }
+
+        deserializer.deserialize_any(ReprVisitor(PhantomData))
+    }
+}
+
+/// Parses a hex bitmask such as `0x00C0`, rejecting bits above the 14 hook flags.
+fn parse_bitmask(bitmask: &str) -> Result<u16, V4HookError> {
+    bitmask
+        .strip_prefix("0x")
+        .or_else(|| bitmask.strip_prefix("0X"))
+        .and_then(|digits| u16::from_str_radix(digits, 16).ok())
+        .filter(|flags| flags & !ALL_HOOK_MASK == 0)
+        .ok_or_else(|| V4HookError::InvalidBitmask(bitmask.to_string()))
+}
+
+/// Checks that a numeric bitmask only sets the 14 hook flags.
+fn check_flags(flags: u64) -> Result<u16, V4HookError> {
+    u16::try_from(flags)
+        .ok()
+        .filter(|flags| flags & !ALL_HOOK_MASK == 0)
+        .ok_or_else(|| V4HookError::InvalidBitmask(format!("{flags:#06x}")))
+}
+
+/// Converts `Hooks.Permissions` names into a bitmask.
+fn parse_names<S: AsRef<str>>(names: &[S]) -> Result<u16, V4HookError> {
+    names.iter().try_fold(0, |flags, name| {
+        let name = name.as_ref();
+        PERMISSION_NAMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, flag)| flags | flag)
+            .ok_or_else(|| V4HookError::UnknownPermission(name.to_string()))
+    })
+}
+
+/// Sorts the fields of a permissions object into `PERMISSION_NAMES` order.
+///
+/// ## Returns
+/// The value given for each permission, and whether any field used its Solidity camelCase name.
+fn parse_fields<T>(fields: Vec<(String, T)>) -> Result<([Option<T>; 14], bool), V4HookError> {
+    let mut values: [Option<T>; 14] = Default::default();
+    let mut camel_case = false;
+
+    for (name, value) in fields {
+        let index = match PERMISSION_NAMES.iter().position(|(n, _)| *n == name) {
+            Some(index) => {
+                camel_case = true;
+                index
+            }
+            None => SNAKE_CASE_PERMISSION_NAMES
+                .iter()
+                .position(|n| *n == name)
+                .ok_or(V4HookError::UnknownPermission(name))?,
+        };
+        if values[index].replace(value).is_some() {
+            return Err(V4HookError::DuplicatePermission(PERMISSION_NAMES[index].0));
+        }
+    }
+
+    Ok((values, camel_case))
+}
+
+/// Requires a value for every permission, as a Solidity struct literal does.
+fn require_all_fields<T>(values: [Option<T>; 14]) -> Result<[T; 14], V4HookError> {
+    if let Some(index) = values.iter().position(Option::is_none) {
+        return Err(V4HookError::MissingPermission(PERMISSION_NAMES[index].0));
+    }
+    Ok(values.map(Option::unwrap))
+}
+
 /// The boolean flags for which hooks to enable. Will be converted into a `u16` bitmask.
-#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+///
+/// Besides the struct form (with snake_case or Solidity's camelCase field names, all 14 required),
+/// permissions deserialize from a list of enabled `Hooks.Permissions` names such as
+/// `["beforeSwap", "afterSwap"]`, or from a bitmask given as a hex string (`"0x00C0"`) or number.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "PermissionsRepr<bool>")]
 pub struct V4HookPermissions {
     pub before_initialize: bool,
     pub after_initialize: bool,
@@ -92,6 +337,78 @@ impl V4HookPermissions {
         flags
     }
 
+    /// Converts a `u16` bitmask into boolean permissions, ignoring bits above the 14 hook flags.
+    pub fn from_flags(flags: u16) -> Self {
+        Self {
+            before_initialize: flags & BEFORE_INITIALIZE_FLAG != 0,
+            after_initialize: flags & AFTER_INITIALIZE_FLAG != 0,
+            before_add_liquidity: flags & BEFORE_ADD_LIQUIDITY_FLAG != 0,
+            after_add_liquidity: flags & AFTER_ADD_LIQUIDITY_FLAG != 0,
+            before_remove_liquidity: flags & BEFORE_REMOVE_LIQUIDITY_FLAG != 0,
+            after_remove_liquidity: flags & AFTER_REMOVE_LIQUIDITY_FLAG != 0,
+            before_swap: flags & BEFORE_SWAP_FLAG != 0,
+            after_swap: flags & AFTER_SWAP_FLAG != 0,
+            before_donate: flags & BEFORE_DONATE_FLAG != 0,
+            after_donate: flags & AFTER_DONATE_FLAG != 0,
+            before_swap_return_delta: flags & BEFORE_SWAP_RETURNS_DELTA_FLAG != 0,
+            after_swap_return_delta: flags & AFTER_SWAP_RETURNS_DELTA_FLAG != 0,
+            after_add_liquidity_return_delta: flags & AFTER_ADD_LIQUIDITY_RETURNS_DELTA_FLAG != 0,
+            after_remove_liquidity_return_delta: flags & AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA_FLAG
+                != 0,
+        }
+    }
+
+    /// Converts a list of `Hooks.Permissions` names into permissions.
+    pub fn from_names<S: AsRef<str>>(names: &[S]) -> Result<Self, V4HookError> {
+        parse_names(names).map(Self::from_flags)
+    }
+
+    /// Returns the `Hooks.Permissions` names of the enabled permissions.
+    pub fn to_names(&self) -> Vec<&'static str> {
+        permission_names(self.to_flags())
+    }
+
+    /// Returns the permissions as a Solidity `Hooks.Permissions` struct literal, with every
+    /// field in declaration order.
+    pub fn to_struct_json(&self) -> serde_json::Value {
+        let flags = self.to_flags();
+        PERMISSION_NAMES
+            .iter()
+            .map(|(name, flag)| (name.to_string(), (flags & flag != 0).into()))
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+
+    /// Decodes the permissions encoded in the lowest 14 bits of a hook address.
+    pub fn from_address(address: Address) -> Self {
+        Self::from_flags(u16::from_be_bytes([address[18], address[19]]))
+    }
+
+    /// Checks that every return-delta permission comes with its action permission, as
+    /// `Hooks.isValidHookAddress` requires.
+    pub fn validate(&self) -> Result<(), V4HookError> {
+        let flags = self.to_flags();
+        for (permission, parent) in RETURN_DELTA_PARENTS {
+            if flags & permission != 0 && flags & parent == 0 {
+                return Err(V4HookError::ReturnDeltaWithoutParent {
+                    permission: permission_name(permission),
+                    parent: permission_name(parent),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the permissions of a hook address as `Hooks.isValidHookAddress` does: on top of
+    /// [`Self::validate`], a hook with no flags at all is only accepted by dynamic-fee pools.
+    pub fn validate_for_pool(&self, dynamic_fee: bool) -> Result<(), V4HookError> {
+        self.validate()?;
+        if !dynamic_fee && self.to_flags() == 0 {
+            return Err(V4HookError::NoPermissions);
+        }
+        Ok(())
+    }
+
     /// Converts the boolean permissions into a 2-byte suffix.
     pub fn to_suffix(&self) -> [u8; 2] {
         let flags = self.to_flags();
@@ -102,13 +419,375 @@ impl V4HookPermissions {
     }
 }
 
+impl fmt::Display for V4HookPermissions {
+    /// Formats the permissions as a hex bitmask, e.g. `0x00c0`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#06x}", self.to_flags())
+    }
+}
+
+impl FromStr for V4HookPermissions {
+    type Err = V4HookError;
+
+    /// Parses a hex bitmask such as `0x00C0`.
+    fn from_str(bitmask: &str) -> Result<Self, Self::Err> {
+        parse_bitmask(bitmask).map(Self::from_flags)
+    }
+}
+
+impl TryFrom<PermissionsRepr<bool>> for V4HookPermissions {
+    type Error = V4HookError;
+
+    fn try_from(repr: PermissionsRepr<bool>) -> Result<Self, Self::Error> {
+        Ok(match repr {
+            PermissionsRepr::Bitmask(bitmask) => bitmask.parse()?,
+            PermissionsRepr::Flags(flags) => Self::from_flags(check_flags(flags)?),
+            PermissionsRepr::Names(names) => Self::from_names(&names)?,
+            PermissionsRepr::Fields(fields) => {
+                let (values, _) = parse_fields(fields)?;
+                let flags = require_all_fields(values)?
+                    .into_iter()
+                    .zip(PERMISSION_NAMES)
+                    .filter(|(enabled, _)| *enabled)
+                    .fold(0, |flags, (_, (_, flag))| flags | flag);
+                Self::from_flags(flags)
+            }
+        })
+    }
+}
+
+/// Decodes and validates an existing hook address.
+///
+/// ## Arguments
+/// * `address` - The hook address.
+/// * `expected` - The permissions the hook declares in `getHookPermissions`, checked as
+///   `Hooks.validateHookPermissions` does.
+/// * `dynamic_fee` - Whether the hook is used by a dynamic-fee pool, which allows it to have no
+///   flags at all.
+///
+/// ## Returns
+/// The permissions encoded in the address, or why the `PoolManager` would reject it.
+pub fn validate_hook_address(
+    address: Address,
+    expected: Option<&V4HookPermissions>,
+    dynamic_fee: bool,
+) -> Result<V4HookPermissions, V4HookError> {
+    let permissions = V4HookPermissions::from_address(address);
+    permissions.validate_for_pool(dynamic_fee)?;
+
+    if let Some(expected) = expected {
+        let flags = permissions.to_flags();
+        let expected_flags = expected.to_flags();
+        if flags != expected_flags {
+            return Err(V4HookError::PermissionMismatch {
+                missing: permission_names(expected_flags & !flags),
+                unexpected: permission_names(flags & !expected_flags),
+            });
+        }
+    }
+
+    Ok(permissions)
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_validate_v4_hook_address(
+    address: JsValue,
+    expected: JsValue,
+    dynamic_fee: bool,
+) -> Result<JsValue, JsValue> {
+    let address: Address = from_value(address).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let expected: Option<V4HookPermissions> =
+        from_value(expected).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let permissions = validate_hook_address(address, expected.as_ref(), dynamic_fee)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&permissions).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Returns the enabled `Hooks.Permissions` names of permissions given in any accepted format.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_v4_hook_permission_names(permissions: JsValue) -> Result<JsValue, JsValue> {
+    let permissions: V4HookPermissions =
+        from_value(permissions).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    to_value(&permissions.to_names()).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Returns the hex bitmask of permissions given in any accepted format.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_v4_hook_permission_bitmask(permissions: JsValue) -> Result<String, JsValue> {
+    let permissions: V4HookPermissions =
+        from_value(permissions).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(permissions.to_string())
+}
+
+/// Whether a hook permission must be set, must be unset, or may be either.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionRequirement {
+    Required,
+    Forbidden,
+    #[default]
+    Any,
+}
+
+impl From<bool> for PermissionRequirement {
+    fn from(enabled: bool) -> Self {
+        if enabled {
+            Self::Required
+        } else {
+            Self::Forbidden
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionRequirement {
+    /// Accepts `"required"`, `"forbidden"`, `"any"`, or a boolean for an exact permission.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RequirementVisitor;
+
+        impl Visitor<'_> for RequirementVisitor {
+            type Value = PermissionRequirement;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a boolean, \"required\", \"forbidden\" or \"any\"")
+            }
+
+            fn visit_bool<E: de::Error>(self, enabled: bool) -> Result<Self::Value, E> {
+                Ok(enabled.into())
+            }
+
+            fn visit_str<E: de::Error>(self, requirement: &str) -> Result<Self::Value, E> {
+                match requirement {
+                    "required" => Ok(PermissionRequirement::Required),
+                    "forbidden" => Ok(PermissionRequirement::Forbidden),
+                    "any" => Ok(PermissionRequirement::Any),
+                    _ => Err(E::custom(V4HookError::InvalidRequirement(
+                        requirement.to_string(),
+                    ))),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(RequirementVisitor)
+    }
+}
+
+/// The hook permissions a mined address must have, must not have, or doesn't care about.
+///
+/// A names list or bitmask, as accepted by `V4HookPermissions`, requires exactly those
+/// permissions and forbids all others, and so does the Solidity `Hooks.Permissions` struct
+/// literal, recognised by its camelCase field names, which must list all 14 fields. Only the
+/// snake_case object form may omit fields, which then default to `Any`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "PermissionsRepr<PermissionRequirement>")]
+pub struct V4HookPermissionSpec {
+    pub before_initialize: PermissionRequirement,
+    pub after_initialize: PermissionRequirement,
+    pub before_add_liquidity: PermissionRequirement,
+    pub after_add_liquidity: PermissionRequirement,
+    pub before_remove_liquidity: PermissionRequirement,
+    pub after_remove_liquidity: PermissionRequirement,
+    pub before_swap: PermissionRequirement,
+    pub after_swap: PermissionRequirement,
+    pub before_donate: PermissionRequirement,
+    pub after_donate: PermissionRequirement,
+    pub before_swap_return_delta: PermissionRequirement,
+    pub after_swap_return_delta: PermissionRequirement,
+    pub after_add_liquidity_return_delta: PermissionRequirement,
+    pub after_remove_liquidity_return_delta: PermissionRequirement,
+}
+
+impl V4HookPermissionSpec {
+    /// Builds a spec from one requirement per permission, in `PERMISSION_NAMES` order.
+    fn from_requirements(requirements: [PermissionRequirement; 14]) -> Self {
+        let [
+            before_initialize,
+            after_initialize,
+            before_add_liquidity,
+            after_add_liquidity,
+            before_remove_liquidity,
+            after_remove_liquidity,
+            before_swap,
+            after_swap,
+            before_donate,
+            after_donate,
+            before_swap_return_delta,
+            after_swap_return_delta,
+            after_add_liquidity_return_delta,
+            after_remove_liquidity_return_delta,
+        ] = requirements;
+
+        Self {
+            before_initialize,
+            after_initialize,
+            before_add_liquidity,
+            after_add_liquidity,
+            before_remove_liquidity,
+            after_remove_liquidity,
+            before_swap,
+            after_swap,
+            before_donate,
+            after_donate,
+            before_swap_return_delta,
+            after_swap_return_delta,
+            after_add_liquidity_return_delta,
+            after_remove_liquidity_return_delta,
+        }
+    }
+
+    /// Pairs each requirement with its flag.
+    fn requirements(&self) -> [(PermissionRequirement, u16); 14] {
+        [
+            (self.before_initialize, BEFORE_INITIALIZE_FLAG),
+            (self.after_initialize, AFTER_INITIALIZE_FLAG),
+            (self.before_add_liquidity, BEFORE_ADD_LIQUIDITY_FLAG),
+            (self.after_add_liquidity, AFTER_ADD_LIQUIDITY_FLAG),
+            (self.before_remove_liquidity, BEFORE_REMOVE_LIQUIDITY_FLAG),
+            (self.after_remove_liquidity, AFTER_REMOVE_LIQUIDITY_FLAG),
+            (self.before_swap, BEFORE_SWAP_FLAG),
+            (self.after_swap, AFTER_SWAP_FLAG),
+            (self.before_donate, BEFORE_DONATE_FLAG),
+            (self.after_donate, AFTER_DONATE_FLAG),
+            (
+                self.before_swap_return_delta,
+                BEFORE_SWAP_RETURNS_DELTA_FLAG,
+            ),
+            (self.after_swap_return_delta, AFTER_SWAP_RETURNS_DELTA_FLAG),
+            (
+                self.after_add_liquidity_return_delta,
+                AFTER_ADD_LIQUIDITY_RETURNS_DELTA_FLAG,
+            ),
+            (
+                self.after_remove_liquidity_return_delta,
+                AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA_FLAG,
+            ),
+        ]
+    }
+
+    /// Rejects specs whose addresses `Hooks.isValidHookAddress` would always refuse: a required
+    /// return-delta permission whose action permission is not also required, or, for static-fee
+    /// pools, every permission forbidden.
+    pub fn validate(&self, dynamic_fee: bool) -> Result<(), V4HookError> {
+        let mask = self.to_mask();
+        V4HookPermissions::from_flags(mask.flags).validate()?;
+        if !dynamic_fee && mask.mask == ALL_HOOK_MASK && mask.flags == 0 {
+            return Err(V4HookError::NoPermissions);
+        }
+        Ok(())
+    }
+
+    /// Compiles the requirements into the flag bits to check and their expected values.
+    pub fn to_mask(&self) -> V4HookMask {
+        let mut mask = V4HookMask::default();
+        for (requirement, flag) in self.requirements() {
+            match requirement {
+                PermissionRequirement::Required => {
+                    mask.mask |= flag;
+                    mask.flags |= flag;
+                }
+                PermissionRequirement::Forbidden => mask.mask |= flag,
+                PermissionRequirement::Any => {}
+            }
+        }
+        mask
+    }
+}
+
+impl From<V4HookPermissions> for V4HookPermissionSpec {
+    fn from(permissions: V4HookPermissions) -> Self {
+        Self {
+            before_initialize: permissions.before_initialize.into(),
+            after_initialize: permissions.after_initialize.into(),
+            before_add_liquidity: permissions.before_add_liquidity.into(),
+            after_add_liquidity: permissions.after_add_liquidity.into(),
+            before_remove_liquidity: permissions.before_remove_liquidity.into(),
+            after_remove_liquidity: permissions.after_remove_liquidity.into(),
+            before_swap: permissions.before_swap.into(),
+            after_swap: permissions.after_swap.into(),
+            before_donate: permissions.before_donate.into(),
+            after_donate: permissions.after_donate.into(),
+            before_swap_return_delta: permissions.before_swap_return_delta.into(),
+            after_swap_return_delta: permissions.after_swap_return_delta.into(),
+            after_add_liquidity_return_delta: permissions.after_add_liquidity_return_delta.into(),
+            after_remove_liquidity_return_delta: permissions
+                .after_remove_liquidity_return_delta
+                .into(),
+        }
+    }
+}
+
+impl TryFrom<PermissionsRepr<PermissionRequirement>> for V4HookPermissionSpec {
+    type Error = V4HookError;
+
+    fn try_from(repr: PermissionsRepr<PermissionRequirement>) -> Result<Self, Self::Error> {
+        Ok(match repr {
+            PermissionsRepr::Bitmask(bitmask) => bitmask.parse::<V4HookPermissions>()?.into(),
+            PermissionsRepr::Flags(flags) => {
+                V4HookPermissions::from_flags(check_flags(flags)?).into()
+            }
+            PermissionsRepr::Names(names) => V4HookPermissions::from_names(&names)?.into(),
+            PermissionsRepr::Fields(fields) => {
+                let (values, camel_case) = parse_fields(fields)?;
+                let requirements = if camel_case {
+                    require_all_fields(values)?
+                } else {
+                    values.map(Option::unwrap_or_default)
+                };
+                Self::from_requirements(requirements)
+            }
+        })
+    }
+}
+
+/// The flag bits of a hook address to check (`mask`) and the values they must have (`flags`).
+///
+/// Only the lowest 14 bits of an address encode hook permissions, the rest are free.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct V4HookMask {
+    pub mask: u16,
+    pub flags: u16,
+}
+
+impl V4HookMask {
+    /// Returns whether the address carries the required flags.
+    pub fn matches(&self, address: Address) -> bool {
+        let low_bits = u16::from_be_bytes([address[18], address[19]]);
+        low_bits & self.mask == self.flags
+    }
+
+    /// Returns whether the address carries the required flags and is accepted by
+    /// `Hooks.isValidHookAddress`, which matters when flags are left as `Any`.
+    pub fn matches_valid(&self, address: Address, dynamic_fee: bool) -> bool {
+        self.matches(address)
+            && V4HookPermissions::from_address(address)
+                .validate_for_pool(dynamic_fee)
+                .is_ok()
+    }
+
+    /// The expected number of attempts to find a matching address.
+    pub fn difficulty(&self) -> u64 {
+        1 << self.mask.count_ones()
+    }
+}
+
 /// Configuration for the Uniswap v4 Hook mining process.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct V4HookConfig {
     /// The address that will deploy the hook contract.
     pub deployer: Address,
-    /// The desired hook permissions for the resulting address.
-    pub permissions: V4HookPermissions,
+    /// The desired hook permissions for the resulting address. Each permission is either a
+    /// boolean for an exact value, or `"required"`, `"forbidden"` or `"any"`; omitted
+    /// permissions default to `"any"`.
+    pub permissions: V4HookPermissionSpec,
+    /// Whether the hook is used by dynamic-fee pools, which accept hook addresses without any
+    /// permission flag.
+    #[serde(default)]
+    pub dynamic_fee: bool,
     /// The init code hash to use for the CREATE2 address.
     pub init_code_hash: B256,
     #[cfg_attr(
@@ -130,8 +809,488 @@ pub struct V4HookConfig {
     pub seed: Option<u128>,
 }
 
+impl V4HookConfig {
+    /// Validates the permission spec.
+    pub fn validate(&self) -> Result<(), V4HookError> {
+        self.permissions.validate(self.dynamic_fee)
+    }
+}
+
 /// A single successful match from a v4 Hook mining operation.
 pub type V4HookMatch = Create2Match;
 
 /// Result structure that includes matches and total iterations.
 pub type V4HookResult = Create2Result;
+
+/// Configuration for mining a Uniswap v4 hook deployed via CREATE3, through CreateX by default.
+///
+/// The hook address no longer depends on the init code, so constructor arguments can change
+/// after mining. As in `Create3Config`, legacy `caller` and `chain_id` fields are mapped to
+/// `sender_protection` and `cross_chain_protection`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "V4HookCreate3ConfigRepr")]
+pub struct V4HookCreate3Config {
+    /// The address of the contract deploying the CREATE3 proxy, e.g. the CreateX factory.
+    pub deployer: Address,
+    /// The CREATE3 implementation to mine for.
+    pub scheme: Create3Scheme,
+    /// Permissioned deploy protection encoded in salt bytes 0..20. CreateX only.
+    pub sender_protection: SenderProtection,
+    /// Cross-chain redeploy protection encoded in salt byte 20. CreateX only.
+    pub cross_chain_protection: CrossChainProtection,
+    /// The desired hook permissions for the resulting address, as in `V4HookConfig`.
+    pub permissions: V4HookPermissionSpec,
+    /// Whether the hook is used by dynamic-fee pools, which accept hook addresses without any
+    /// permission flag.
+    pub dynamic_fee: bool,
+    /// The maximum number of attempts before giving up.
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    pub max_results: u64,
+    /// Optional layout of fixed and mined salt bytes.
+    pub salt_template: Option<SaltTemplate>,
+    /// Seed for the random number generator.
+    pub seed: Option<u128>,
+}
+
+/// The serialized form of `V4HookCreate3Config`, including the legacy salt flag fields.
+#[derive(Deserialize)]
+struct V4HookCreate3ConfigRepr {
+    deployer: Address,
+    #[serde(default)]
+    scheme: Create3Scheme,
+    #[serde(default)]
+    sender_protection: Option<SenderProtection>,
+    #[serde(default)]
+    cross_chain_protection: Option<CrossChainProtection>,
+    #[serde(default)]
+    caller: Option<Address>,
+    #[serde(default)]
+    chain_id: Option<ChainId>,
+    permissions: V4HookPermissionSpec,
+    #[serde(default)]
+    dynamic_fee: bool,
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    max_iterations: u64,
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    max_results: u64,
+    #[serde(default)]
+    salt_template: Option<SaltTemplate>,
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_option_u128")
+    )]
+    seed: Option<u128>,
+}
+
+impl TryFrom<V4HookCreate3ConfigRepr> for V4HookCreate3Config {
+    type Error = ConfigError;
+
+    fn try_from(repr: V4HookCreate3ConfigRepr) -> Result<Self, Self::Error> {
+        let (sender_protection, cross_chain_protection) = resolve_legacy_protection(
+            repr.sender_protection,
+            repr.cross_chain_protection,
+            repr.caller,
+            repr.chain_id,
+        )?;
+
+        Ok(Self {
+            deployer: repr.deployer,
+            scheme: repr.scheme,
+            sender_protection,
+            cross_chain_protection,
+            permissions: repr.permissions,
+            dynamic_fee: repr.dynamic_fee,
+            max_iterations: repr.max_iterations,
+            max_results: repr.max_results,
+            salt_template: repr.salt_template,
+            seed: repr.seed,
+        })
+    }
+}
+
+impl V4HookCreate3Config {
+    /// Returns the underlying CREATE3 mining configuration.
+    pub fn create3_config(&self) -> Create3Config {
+        Create3Config {
+            deployer: self.deployer,
+            scheme: self.scheme.clone(),
+            sender_protection: self.sender_protection,
+            cross_chain_protection: self.cross_chain_protection,
+            max_iterations: self.max_iterations,
+            max_results: self.max_results,
+            salt_template: self.salt_template.clone(),
+            seed: self.seed,
+        }
+    }
+
+    /// Validates the permission spec and the CREATE3 salt flags.
+    pub fn validate(&self) -> Result<(), V4HookError> {
+        self.permissions.validate(self.dynamic_fee)?;
+        self.create3_config().validate()?;
+        Ok(())
+    }
+}
+
+/// A single successful match from a CREATE3 v4 Hook mining operation.
+pub type V4HookCreate3Match = Create3Match;
+
+/// Result structure that includes CREATE3 matches and total iterations.
+pub type V4HookCreate3Result = Create3Result;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_from_address_round_trip() {
+        for (_, flag) in PERMISSION_NAMES {
+            assert_eq!(V4HookPermissions::from_flags(flag).to_flags(), flag);
+        }
+
+        let address = address!("00000000000000000000000000000000000020c8");
+        let permissions = V4HookPermissions::from_address(address);
+        assert_eq!(
+            permissions.to_flags(),
+            BEFORE_INITIALIZE_FLAG
+                | BEFORE_SWAP_FLAG
+                | AFTER_SWAP_FLAG
+                | BEFORE_SWAP_RETURNS_DELTA_FLAG
+        );
+        assert_eq!(
+            V4HookPermissions::from_address(address!("000000000000000000000000000000000000e0c8")),
+            permissions,
+            "Bits above the 14 hook flags must be ignored"
+        );
+    }
+
+    #[test]
+    fn test_validate_hook_address() {
+        assert_eq!(
+            validate_hook_address(
+                address!("0000000000000000000000000000000000000008"),
+                None,
+                false
+            ),
+            Err(V4HookError::ReturnDeltaWithoutParent {
+                permission: "beforeSwapReturnDelta",
+                parent: "beforeSwap"
+            })
+        );
+
+        let expected = V4HookPermissions {
+            before_swap: true,
+            before_donate: true,
+            ..Default::default()
+        };
+        let error = validate_hook_address(
+            address!("00000000000000000000000000000000000000c0"),
+            Some(&expected),
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            V4HookError::PermissionMismatch {
+                missing: vec!["beforeDonate"],
+                unexpected: vec!["afterSwap"]
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "hook address is missing [\"beforeDonate\"] and has unexpected [\"afterSwap\"]"
+        );
+
+        assert_eq!(
+            validate_hook_address(
+                address!("00000000000000000000000000000000000000a0"),
+                Some(&expected),
+                false
+            ),
+            Ok(expected)
+        );
+
+        let no_flags = address!("000000000000000000000000000000000000c000");
+        assert_eq!(
+            validate_hook_address(no_flags, None, false),
+            Err(V4HookError::NoPermissions),
+            "Static-fee pools reject hooks without flags"
+        );
+        assert_eq!(
+            validate_hook_address(no_flags, None, true),
+            Ok(V4HookPermissions::default())
+        );
+    }
+
+    #[test]
+    fn test_spec_validation() {
+        let permissions = V4HookPermissions {
+            before_swap_return_delta: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            V4HookPermissionSpec::from(permissions).validate(false),
+            Err(V4HookError::ReturnDeltaWithoutParent {
+                permission: "beforeSwapReturnDelta",
+                parent: "beforeSwap"
+            })
+        );
+
+        let spec = V4HookPermissionSpec {
+            after_add_liquidity_return_delta: PermissionRequirement::Required,
+            ..Default::default()
+        };
+        assert!(
+            spec.validate(false).is_err(),
+            "A required return delta needs its action to be required too"
+        );
+
+        let spec = V4HookPermissionSpec {
+            after_add_liquidity: PermissionRequirement::Required,
+            after_add_liquidity_return_delta: PermissionRequirement::Required,
+            ..Default::default()
+        };
+        assert_eq!(spec.validate(false), Ok(()));
+
+        let none = V4HookPermissionSpec::from(V4HookPermissions::default());
+        assert_eq!(none.validate(false), Err(V4HookError::NoPermissions));
+        assert_eq!(
+            none.validate(true),
+            Ok(()),
+            "Dynamic-fee pools accept hooks without flags"
+        );
+
+        let mask = V4HookPermissionSpec::default().to_mask();
+        assert!(mask.matches(address!("0000000000000000000000000000000000000004")));
+        assert!(
+            !mask.matches_valid(address!("0000000000000000000000000000000000000004"), true),
+            "afterSwapReturnDelta without afterSwap is never valid"
+        );
+        assert!(
+            !mask.matches_valid(address!("000000000000000000000000000000000000c000"), false),
+            "Addresses without flags are only valid for dynamic-fee pools"
+        );
+        assert!(mask.matches_valid(address!("000000000000000000000000000000000000c000"), true));
+    }
+
+    #[test]
+    fn test_spec_to_mask() {
+        let spec = V4HookPermissionSpec {
+            before_swap: PermissionRequirement::Required,
+            after_swap: PermissionRequirement::Forbidden,
+            ..Default::default()
+        };
+        let mask = spec.to_mask();
+        assert_eq!(mask.mask, BEFORE_SWAP_FLAG | AFTER_SWAP_FLAG);
+        assert_eq!(mask.flags, BEFORE_SWAP_FLAG);
+        assert_eq!(
+            mask.difficulty(),
+            4,
+            "Two constrained bits should need 4 attempts"
+        );
+
+        assert!(mask.matches(address!("0000000000000000000000000000000000000080")));
+        assert!(mask.matches(address!("00000000000000000000000000000000ffffffbf")));
+        assert!(!mask.matches(address!("00000000000000000000000000000000000000c0")));
+    }
+
+    #[test]
+    fn test_exact_permissions_ignore_unused_bits() {
+        let permissions = V4HookPermissions {
+            before_swap: true,
+            ..Default::default()
+        };
+        let mask = V4HookPermissionSpec::from(permissions).to_mask();
+        assert_eq!(mask.mask, ALL_HOOK_MASK);
+        assert_eq!(mask.flags, permissions.to_flags());
+        assert!(
+            mask.matches(address!("000000000000000000000000000000000000c080")),
+            "The top 2 bits of the suffix do not encode permissions"
+        );
+    }
+
+    #[test]
+    fn test_permission_formats_round_trip() {
+        for (name, flag) in PERMISSION_NAMES {
+            let permissions = V4HookPermissions::from_flags(flag);
+
+            assert_eq!(permissions.to_names(), vec![name]);
+            assert_eq!(V4HookPermissions::from_names(&[name]), Ok(permissions));
+
+            let bitmask = permissions.to_string();
+            assert_eq!(bitmask, format!("{flag:#06x}"));
+            assert_eq!(bitmask.parse(), Ok(permissions));
+
+            let struct_json = permissions.to_struct_json();
+            assert_eq!(struct_json[name], true);
+            assert_eq!(
+                serde_json::from_value::<V4HookPermissions>(struct_json).unwrap(),
+                permissions
+            );
+            assert_eq!(
+                serde_json::from_value::<V4HookPermissions>(
+                    serde_json::to_value(permissions).unwrap()
+                )
+                .unwrap(),
+                permissions,
+                "The snake_case struct form must still round-trip"
+            );
+        }
+    }
+
+    #[test]
+    fn test_permissions_accept_names_and_bitmasks() {
+        let expected = V4HookPermissions::from_flags(BEFORE_SWAP_FLAG | AFTER_SWAP_FLAG);
+
+        for input in [
+            serde_json::json!(["beforeSwap", "afterSwap"]),
+            serde_json::json!("0x00C0"),
+            serde_json::json!(0xc0),
+        ] {
+            assert_eq!(
+                serde_json::from_value::<V4HookPermissions>(input.clone()).unwrap(),
+                expected,
+                "Failed to parse {input}"
+            );
+        }
+
+        let all = V4HookPermissions::from_flags(ALL_HOOK_MASK);
+        assert_eq!(all.to_string(), "0x3fff");
+        assert_eq!(all.to_names().len(), 14);
+        assert_eq!(V4HookPermissions::from_names(&all.to_names()), Ok(all));
+
+        assert_eq!(
+            V4HookPermissions::from_names(&["beforeSwap", "before_swap"]),
+            Err(V4HookError::UnknownPermission("before_swap".to_string()))
+        );
+        assert_eq!(
+            "0x4000".parse::<V4HookPermissions>(),
+            Err(V4HookError::InvalidBitmask("0x4000".to_string()))
+        );
+        assert_eq!(
+            "00c0".parse::<V4HookPermissions>(),
+            Err(V4HookError::InvalidBitmask("00c0".to_string()))
+        );
+        assert!(serde_json::from_value::<V4HookPermissions>(serde_json::json!(["swap"])).is_err());
+    }
+
+    #[test]
+    fn test_spec_accepts_names_and_bitmasks() {
+        let exact: V4HookPermissionSpec =
+            V4HookPermissions::from_flags(BEFORE_SWAP_FLAG | AFTER_SWAP_FLAG).into();
+
+        for input in [
+            serde_json::json!(["beforeSwap", "afterSwap"]),
+            serde_json::json!("0x00c0"),
+        ] {
+            assert_eq!(
+                serde_json::from_value::<V4HookPermissionSpec>(input).unwrap(),
+                exact
+            );
+        }
+
+        let struct_json =
+            V4HookPermissions::from_flags(BEFORE_SWAP_FLAG | AFTER_SWAP_FLAG).to_struct_json();
+        assert_eq!(
+            serde_json::from_value::<V4HookPermissionSpec>(struct_json).unwrap(),
+            exact,
+            "The Solidity struct literal must pin every flag"
+        );
+    }
+
+    #[test]
+    fn test_spec_rejects_incomplete_solidity_struct() {
+        let err = serde_json::from_value::<V4HookPermissionSpec>(
+            serde_json::json!({ "beforeSwap": true }),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            V4HookError::MissingPermission("beforeInitialize").to_string(),
+            "A partial camelCase struct must not leave omitted flags unconstrained"
+        );
+    }
+
+    #[test]
+    fn test_spec_rejects_misspelled_permissions() {
+        for (input, expected) in [
+            (
+                serde_json::json!({ "before_swap": true, "before_swap_returns_delta": false }),
+                V4HookError::UnknownPermission("before_swap_returns_delta".to_string()),
+            ),
+            (
+                serde_json::json!({ "before_swap": true, "afterSwapp": false }),
+                V4HookError::UnknownPermission("afterSwapp".to_string()),
+            ),
+            (
+                serde_json::json!({ "before_swap": true, "beforeSwap": true }),
+                V4HookError::DuplicatePermission("beforeSwap"),
+            ),
+            (
+                serde_json::json!({ "before_swap": "requried" }),
+                V4HookError::InvalidRequirement("requried".to_string()),
+            ),
+            (
+                serde_json::json!(0x4000),
+                V4HookError::InvalidBitmask("0x4000".to_string()),
+            ),
+        ] {
+            let err = serde_json::from_value::<V4HookPermissionSpec>(input.clone()).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                expected.to_string(),
+                "Unexpected error for {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_spec_accepts_booleans_and_names() {
+        let spec: V4HookPermissionSpec = serde_json::from_value(serde_json::json!({
+            "before_swap": true,
+            "after_swap": false,
+            "before_donate": "required",
+            "after_donate": "any"
+        }))
+        .unwrap();
+        assert_eq!(spec.before_swap, PermissionRequirement::Required);
+        assert_eq!(spec.after_swap, PermissionRequirement::Forbidden);
+        assert_eq!(spec.before_donate, PermissionRequirement::Required);
+        assert_eq!(spec.after_donate, PermissionRequirement::Any);
+        assert_eq!(
+            spec.before_initialize,
+            PermissionRequirement::Any,
+            "Omitted permissions should default to any"
+        );
+    }
+
+    #[test]
+    fn test_create3_config_accepts_legacy_caller_and_chain_id() {
+        let config: V4HookCreate3Config = serde_json::from_value(serde_json::json!({
+            "deployer": "0xba5ed099633d3b313e4d5f7bdc1305d3c28ba5ed",
+            "caller": "0x1111111111111111111111111111111111111111",
+            "chain_id": 130,
+            "permissions": ["beforeSwap"],
+            "max_iterations": 1,
+            "max_results": 1,
+            "seed": null
+        }))
+        .unwrap();
+
+        assert_eq!(
+            config.sender_protection,
+            SenderProtection::Caller(address!("1111111111111111111111111111111111111111"))
+        );
+        assert_eq!(
+            config.cross_chain_protection,
+            CrossChainProtection::Enabled(130)
+        );
+    }
+}
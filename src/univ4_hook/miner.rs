@@ -1,7 +1,11 @@
 use crate::{
-    createx::{Create2Config, mine_create2_salt_with_suffix},
-    univ4_hook::config::{V4HookConfig, V4HookResult},
+    createx::{Create2Config, mine_create2_salt, mine_create3_salt},
+    pattern::{anchored_difficulty, contains_difficulty, contains_predicate, prefix_predicate},
+    univ4_hook::config::{
+        V4HookConfig, V4HookCreate3Config, V4HookCreate3Result, V4HookError, V4HookResult,
+    },
 };
+use alloy_primitives::Address;
 #[cfg(target_arch = "wasm32")]
 use serde_wasm_bindgen::{from_value, to_value};
 #[cfg(target_arch = "wasm32")]
@@ -9,22 +13,221 @@ use wasm_bindgen::prelude::*;
 
 /// Mines for a salt that produces a Uniswap v4 hook address satisfying the given permission flags.
 ///
+/// Permissions set to `Any` are left unconstrained, so each one halves the expected work.
+/// Addresses that `Hooks.isValidHookAddress` would reject are skipped.
+///
 /// ## Arguments
 /// * `config` - A `V4HookConfig` struct defining the mining parameters.
 ///
 /// ## Returns
 /// A `V4HookResult` containing the found salts/addresses and total iterations.
+///
+/// ## Panics
+/// If the config is invalid, see `try_mine_v4_hook_salt`.
 pub fn mine_v4_hook_salt(config: &V4HookConfig) -> V4HookResult {
+    mine_v4_hook_salt_matching(config, &|_| true)
+}
+
+/// Mines for a salt that produces a hook address satisfying both the permission flags and an
+/// additional predicate, e.g. a vanity prefix.
+///
+/// ## Arguments
+/// * `config` - A `V4HookConfig` struct defining the mining parameters.
+/// * `pattern` - A closure that returns true if the computed address has the desired pattern.
+///
+/// ## Returns
+/// A `V4HookResult` containing the found salts/addresses and total iterations.
+///
+/// ## Panics
+/// If the config is invalid, see `try_mine_v4_hook_salt_matching`.
+pub fn mine_v4_hook_salt_matching(
+    config: &V4HookConfig,
+    pattern: &dyn Fn(Address) -> bool,
+) -> V4HookResult {
+    try_mine_v4_hook_salt_matching(config, pattern)
+        .unwrap_or_else(|e| panic!("Invalid config: {e}"))
+}
+
+/// Mines for a salt that produces a hook address with the permission flags and a specific prefix.
+pub fn mine_v4_hook_salt_with_prefix(config: &V4HookConfig, prefix: &[u8]) -> V4HookResult {
+    mine_v4_hook_salt_matching(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a salt that produces a hook address with the permission flags and containing a
+/// specific byte sequence.
+pub fn mine_v4_hook_salt_with_contains(config: &V4HookConfig, contains: &[u8]) -> V4HookResult {
+    mine_v4_hook_salt_matching(config, &contains_predicate(contains))
+}
+
+/// Like `mine_v4_hook_salt`, but returns an error instead of panicking on an invalid config.
+pub fn try_mine_v4_hook_salt(config: &V4HookConfig) -> Result<V4HookResult, V4HookError> {
+    try_mine_v4_hook_salt_matching(config, &|_| true)
+}
+
+/// Mines for a salt that produces a hook address satisfying both the permission flags and an
+/// additional predicate.
+///
+/// ## Arguments
+/// * `config` - A `V4HookConfig` struct defining the mining parameters.
+/// * `pattern` - A closure that returns true if the computed address has the desired pattern.
+///
+/// ## Returns
+/// A `V4HookResult` containing the found salts/addresses and total iterations, or the
+/// `V4HookError` explaining why the permission spec can never be satisfied.
+pub fn try_mine_v4_hook_salt_matching(
+    config: &V4HookConfig,
+    pattern: &dyn Fn(Address) -> bool,
+) -> Result<V4HookResult, V4HookError> {
+    config.validate()?;
     let create2_config = Create2Config {
         deployer: config.deployer,
         init_code_hash: config.init_code_hash,
         max_iterations: config.max_iterations,
         max_results: config.max_results,
         seed: config.seed,
+        salt_template: None,
+    };
+    let mask = config.permissions.to_mask();
+
+    Ok(mine_create2_salt(&create2_config, &|address| {
+        mask.matches_valid(address, config.dynamic_fee) && pattern(address)
+    }))
+}
+
+/// Like `mine_v4_hook_salt_with_prefix`, but returns an error on an invalid config.
+pub fn try_mine_v4_hook_salt_with_prefix(
+    config: &V4HookConfig,
+    prefix: &[u8],
+) -> Result<V4HookResult, V4HookError> {
+    try_mine_v4_hook_salt_matching(config, &prefix_predicate(prefix))
+}
+
+/// Like `mine_v4_hook_salt_with_contains`, but returns an error on an invalid config.
+pub fn try_mine_v4_hook_salt_with_contains(
+    config: &V4HookConfig,
+    contains: &[u8],
+) -> Result<V4HookResult, V4HookError> {
+    try_mine_v4_hook_salt_matching(config, &contains_predicate(contains))
+}
+
+/// Estimates the expected number of attempts to find a hook address with the permission flags
+/// and a specific prefix. Flag bits already pinned by a prefix reaching the last two bytes are
+/// only counted once.
+pub fn v4_hook_prefix_difficulty(config: &V4HookConfig, prefix: &[u8]) -> f64 {
+    let mask = config.permissions.to_mask();
+    let pinned = match prefix.len() {
+        0..=18 => 0,
+        19 => 0xff00,
+        _ => 0xffff,
     };
-    let suffix = config.permissions.to_suffix();
 
-    mine_create2_salt_with_suffix(&create2_config, &suffix)
+    anchored_difficulty(prefix.len()) * 2f64.powi((mask.mask & !pinned).count_ones() as i32)
+}
+
+/// Estimates the expected number of attempts to find a hook address with the permission flags
+/// and containing a specific byte sequence.
+pub fn v4_hook_contains_difficulty(config: &V4HookConfig, contains: &[u8]) -> f64 {
+    contains_difficulty(contains.len()) * config.permissions.to_mask().difficulty() as f64
+}
+
+/// Mines for a CREATE3 salt whose deployed hook address satisfies the given permission flags.
+///
+/// The CreateX caller and chain guard flags are written into the salt exactly as for
+/// `mine_create3_salt`, so the returned guarded salt is the one CreateX will use.
+///
+/// ## Arguments
+/// * `config` - A `V4HookCreate3Config` struct defining the mining parameters.
+///
+/// ## Returns
+/// A `V4HookCreate3Result` containing the found salts/addresses and total iterations.
+///
+/// ## Panics
+/// If the config is invalid, see `try_mine_v4_hook_create3_salt`.
+pub fn mine_v4_hook_create3_salt(config: &V4HookCreate3Config) -> V4HookCreate3Result {
+    mine_v4_hook_create3_salt_matching(config, &|_| true)
+}
+
+/// Mines for a CREATE3 salt whose hook address satisfies both the permission flags and an
+/// additional predicate.
+///
+/// ## Arguments
+/// * `config` - A `V4HookCreate3Config` struct defining the mining parameters.
+/// * `pattern` - A closure that returns true if the computed address has the desired pattern.
+///
+/// ## Returns
+/// A `V4HookCreate3Result` containing the found salts/addresses and total iterations.
+///
+/// ## Panics
+/// If the config is invalid, see `try_mine_v4_hook_create3_salt_matching`.
+pub fn mine_v4_hook_create3_salt_matching(
+    config: &V4HookCreate3Config,
+    pattern: &dyn Fn(Address) -> bool,
+) -> V4HookCreate3Result {
+    try_mine_v4_hook_create3_salt_matching(config, pattern)
+        .unwrap_or_else(|e| panic!("Invalid config: {e}"))
+}
+
+/// Mines for a CREATE3 salt whose hook address has the permission flags and a specific prefix.
+pub fn mine_v4_hook_create3_salt_with_prefix(
+    config: &V4HookCreate3Config,
+    prefix: &[u8],
+) -> V4HookCreate3Result {
+    mine_v4_hook_create3_salt_matching(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a CREATE3 salt whose hook address has the permission flags and contains a specific
+/// byte sequence.
+pub fn mine_v4_hook_create3_salt_with_contains(
+    config: &V4HookCreate3Config,
+    contains: &[u8],
+) -> V4HookCreate3Result {
+    mine_v4_hook_create3_salt_matching(config, &contains_predicate(contains))
+}
+
+/// Like `mine_v4_hook_create3_salt`, but returns an error instead of panicking on an invalid
+/// config.
+pub fn try_mine_v4_hook_create3_salt(
+    config: &V4HookCreate3Config,
+) -> Result<V4HookCreate3Result, V4HookError> {
+    try_mine_v4_hook_create3_salt_matching(config, &|_| true)
+}
+
+/// Mines for a CREATE3 salt whose hook address satisfies both the permission flags and an
+/// additional predicate.
+///
+/// ## Arguments
+/// * `config` - A `V4HookCreate3Config` struct defining the mining parameters.
+/// * `pattern` - A closure that returns true if the computed address has the desired pattern.
+///
+/// ## Returns
+/// A `V4HookCreate3Result` containing the found salts/addresses and total iterations, or the
+/// `V4HookError` explaining why the permission spec or CREATE3 salt layout is invalid.
+pub fn try_mine_v4_hook_create3_salt_matching(
+    config: &V4HookCreate3Config,
+    pattern: &dyn Fn(Address) -> bool,
+) -> Result<V4HookCreate3Result, V4HookError> {
+    config.validate()?;
+    let mask = config.permissions.to_mask();
+
+    Ok(mine_create3_salt(&config.create3_config(), &|address| {
+        mask.matches_valid(address, config.dynamic_fee) && pattern(address)
+    }))
+}
+
+/// Like `mine_v4_hook_create3_salt_with_prefix`, but returns an error on an invalid config.
+pub fn try_mine_v4_hook_create3_salt_with_prefix(
+    config: &V4HookCreate3Config,
+    prefix: &[u8],
+) -> Result<V4HookCreate3Result, V4HookError> {
+    try_mine_v4_hook_create3_salt_matching(config, &prefix_predicate(prefix))
+}
+
+/// Like `mine_v4_hook_create3_salt_with_contains`, but returns an error on an invalid config.
+pub fn try_mine_v4_hook_create3_salt_with_contains(
+    config: &V4HookCreate3Config,
+    contains: &[u8],
+) -> Result<V4HookCreate3Result, V4HookError> {
+    try_mine_v4_hook_create3_salt_matching(config, &contains_predicate(contains))
 }
 
 // WASM wrapper functions
@@ -32,7 +235,322 @@ pub fn mine_v4_hook_salt(config: &V4HookConfig) -> V4HookResult {
 #[wasm_bindgen]
 pub fn wasm_mine_v4_hook_salt(config: JsValue) -> Result<JsValue, JsValue> {
     let config: V4HookConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mining_result =
+        try_mine_v4_hook_salt(&config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_v4_hook_salt_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: V4HookConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mining_result = try_mine_v4_hook_salt_with_prefix(&config, prefix)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_v4_hook_salt_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: V4HookConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mining_result = try_mine_v4_hook_salt_with_contains(&config, contains)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_v4_hook_prefix_difficulty(config: JsValue, prefix: &[u8]) -> Result<f64, JsValue> {
+    let config: V4HookConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(v4_hook_prefix_difficulty(&config, prefix))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_v4_hook_contains_difficulty(config: JsValue, contains: &[u8]) -> Result<f64, JsValue> {
+    let config: V4HookConfig = from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(v4_hook_contains_difficulty(&config, contains))
+}
 
-    let mining_result = mine_v4_hook_salt(&config);
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_v4_hook_create3_salt(config: JsValue) -> Result<JsValue, JsValue> {
+    let config: V4HookCreate3Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mining_result =
+        try_mine_v4_hook_create3_salt(&config).map_err(|e| JsValue::from_str(&e.to_string()))?;
     to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
 }
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_v4_hook_create3_salt_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: V4HookCreate3Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mining_result = try_mine_v4_hook_create3_salt_with_prefix(&config, prefix)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_v4_hook_create3_salt_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: V4HookCreate3Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mining_result = try_mine_v4_hook_create3_salt_with_contains(&config, contains)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::createx::{
+        Create3Scheme, CrossChainProtection, SenderProtection, create3_address, guarded_salt,
+    };
+    use crate::univ4_hook::config::{
+        BEFORE_SWAP_FLAG, PermissionRequirement, V4HookPermissionSpec, V4HookPermissions,
+    };
+    use alloy_primitives::{address, keccak256};
+
+    #[test]
+    fn test_mine_v4_hook_with_optional_permissions() {
+        let config = V4HookConfig {
+            deployer: address!("4e59b44847b379578588920ca78fbf26c0b4956c"),
+            dynamic_fee: false,
+            permissions: V4HookPermissionSpec {
+                before_swap: PermissionRequirement::Required,
+                after_swap: PermissionRequirement::Forbidden,
+                ..Default::default()
+            },
+            init_code_hash: keccak256([0x60, 0x00, 0x60, 0x00, 0xf3]),
+            max_iterations: 1_000,
+            max_results: 3,
+            seed: Some(1234),
+        };
+
+        let mining_result = mine_v4_hook_salt(&config);
+        assert_eq!(
+            mining_result.results.len(),
+            3,
+            "Two constrained bits should match roughly every 4th salt"
+        );
+        for result in &mining_result.results {
+            let low_bits =
+                u16::from_be_bytes([result.computed_address[18], result.computed_address[19]]);
+            assert_eq!(
+                low_bits & 0xc0,
+                0x80,
+                "beforeSwap must be set and afterSwap unset"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mine_v4_hook_with_prefix() {
+        let config = V4HookConfig {
+            deployer: address!("4e59b44847b379578588920ca78fbf26c0b4956c"),
+            dynamic_fee: false,
+            permissions: V4HookPermissionSpec {
+                before_swap: PermissionRequirement::Required,
+                after_swap: PermissionRequirement::Forbidden,
+                ..Default::default()
+            },
+            init_code_hash: keccak256([0x60, 0x00, 0x60, 0x00, 0xf3]),
+            max_iterations: 100_000,
+            max_results: 1,
+            seed: Some(1234),
+        };
+
+        let prefix = &[0xc0];
+        assert_eq!(v4_hook_prefix_difficulty(&config, prefix), 1024.0);
+
+        let mining_result = mine_v4_hook_salt_with_prefix(&config, prefix);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find hook address starting with 0xc0 within {} iterations",
+            config.max_iterations
+        );
+
+        let address = mining_result.results[0].computed_address;
+        assert!(address.starts_with(prefix));
+        assert!(
+            config.permissions.to_mask().matches_valid(address, false),
+            "The hook flags must still hold"
+        );
+    }
+
+    #[test]
+    fn test_v4_hook_difficulty_counts_pinned_bits_once() {
+        let config = V4HookConfig {
+            deployer: Address::ZERO,
+            dynamic_fee: false,
+            permissions: V4HookPermissionSpec {
+                before_initialize: PermissionRequirement::Required,
+                before_swap: PermissionRequirement::Required,
+                ..Default::default()
+            },
+            init_code_hash: Default::default(),
+            max_iterations: 1,
+            max_results: 1,
+            seed: None,
+        };
+
+        assert_eq!(
+            v4_hook_prefix_difficulty(&config, &[0u8; 19]),
+            256f64.powi(19) * 2.0,
+            "Only the flag in the last byte is left to chance"
+        );
+        assert_eq!(
+            v4_hook_contains_difficulty(&config, &[0xc0, 0xde]),
+            65536.0 / 19.0 * 4.0
+        );
+    }
+
+    #[test]
+    fn test_mine_v4_hook_via_createx_create3() {
+        let caller = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        let chain_id = 130;
+        let config = V4HookCreate3Config {
+            deployer: address!("ba5ed099633d3b313e4d5f7bdc1305d3c28ba5ed"),
+            dynamic_fee: false,
+            scheme: Default::default(),
+            sender_protection: SenderProtection::Caller(caller),
+            cross_chain_protection: CrossChainProtection::Enabled(chain_id),
+            permissions: V4HookPermissionSpec {
+                before_swap: PermissionRequirement::Required,
+                after_swap: PermissionRequirement::Required,
+                before_swap_return_delta: PermissionRequirement::Forbidden,
+                ..Default::default()
+            },
+            max_iterations: 10_000,
+            max_results: 2,
+            salt_template: None,
+            seed: Some(1234),
+        };
+
+        let mining_result = mine_v4_hook_create3_salt(&config);
+        assert_eq!(
+            mining_result.results.len(),
+            2,
+            "Failed to find 2 hook addresses within {} iterations",
+            config.max_iterations
+        );
+
+        for result in &mining_result.results {
+            assert_eq!(&result.salt[0..20], caller.as_slice());
+            assert_eq!(result.salt[20], 0x01, "Cross-chain protection flag");
+            assert_eq!(
+                result.guarded_salt,
+                guarded_salt(result.salt, Some(caller), Some(chain_id))
+            );
+            assert_eq!(
+                result.computed_address,
+                create3_address(config.deployer, result.guarded_salt)
+            );
+            assert_eq!(result.computed_address[19] & 0xc8, 0xc0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "salt protection flags are only interpreted by CreateX")]
+    fn test_mine_v4_hook_create3_rejects_guard_flags_for_other_schemes() {
+        let config = V4HookCreate3Config {
+            deployer: Address::ZERO,
+            dynamic_fee: false,
+            scheme: Create3Scheme::Solady,
+            sender_protection: SenderProtection::Caller(Address::ZERO),
+            cross_chain_protection: CrossChainProtection::Disabled,
+            permissions: Default::default(),
+            max_iterations: 1,
+            max_results: 1,
+            salt_template: None,
+            seed: None,
+        };
+
+        mine_v4_hook_create3_salt(&config);
+    }
+
+    #[test]
+    #[should_panic(expected = "only accepted by dynamic-fee pools")]
+    fn test_mine_v4_hook_rejects_static_fee_hook_without_flags() {
+        let config = V4HookConfig {
+            deployer: Address::ZERO,
+            dynamic_fee: false,
+            permissions: V4HookPermissions::default().into(),
+            init_code_hash: Default::default(),
+            max_iterations: 1,
+            max_results: 1,
+            seed: None,
+        };
+
+        mine_v4_hook_salt(&config);
+    }
+
+    #[test]
+    #[should_panic(expected = "beforeSwapReturnDelta requires beforeSwap")]
+    fn test_mine_v4_hook_rejects_return_delta_without_parent() {
+        let config = V4HookConfig {
+            deployer: address!("4e59b44847b379578588920ca78fbf26c0b4956c"),
+            dynamic_fee: false,
+            permissions: V4HookPermissionSpec {
+                before_swap_return_delta: PermissionRequirement::Required,
+                ..Default::default()
+            },
+            init_code_hash: keccak256([0x60, 0x00, 0x60, 0x00, 0xf3]),
+            max_iterations: 1_000,
+            max_results: 1,
+            seed: None,
+        };
+
+        mine_v4_hook_salt(&config);
+    }
+
+    #[test]
+    fn test_try_mine_v4_hook_returns_config_errors() {
+        let config = V4HookConfig {
+            deployer: Address::ZERO,
+            dynamic_fee: false,
+            permissions: V4HookPermissions::default().into(),
+            init_code_hash: Default::default(),
+            max_iterations: 1,
+            max_results: 1,
+            seed: None,
+        };
+        assert_eq!(
+            try_mine_v4_hook_salt(&config).unwrap_err(),
+            V4HookError::NoPermissions
+        );
+
+        let config = V4HookCreate3Config {
+            deployer: Address::ZERO,
+            dynamic_fee: false,
+            scheme: Create3Scheme::Solady,
+            sender_protection: SenderProtection::Caller(Address::ZERO),
+            cross_chain_protection: CrossChainProtection::Disabled,
+            permissions: V4HookPermissions::from_flags(BEFORE_SWAP_FLAG).into(),
+            max_iterations: 1,
+            max_results: 1,
+            salt_template: None,
+            seed: None,
+        };
+        assert!(matches!(
+            try_mine_v4_hook_create3_salt(&config),
+            Err(V4HookError::Create3(_))
+        ));
+    }
+}
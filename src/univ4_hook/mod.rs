@@ -1,5 +1,18 @@
 pub mod config;
 pub mod miner;
 
-pub use config::{V4HookConfig, V4HookMatch, V4HookPermissions, V4HookResult};
-pub use miner::mine_v4_hook_salt;
+pub use config::{
+    PERMISSION_NAMES, PermissionRequirement, V4HookConfig, V4HookCreate3Config, V4HookCreate3Match,
+    V4HookCreate3Result, V4HookError, V4HookMask, V4HookMatch, V4HookPermissionSpec,
+    V4HookPermissions, V4HookResult, validate_hook_address,
+};
+pub use miner::{
+    mine_v4_hook_create3_salt, mine_v4_hook_create3_salt_matching,
+    mine_v4_hook_create3_salt_with_contains, mine_v4_hook_create3_salt_with_prefix,
+    mine_v4_hook_salt, mine_v4_hook_salt_matching, mine_v4_hook_salt_with_contains,
+    mine_v4_hook_salt_with_prefix, try_mine_v4_hook_create3_salt,
+    try_mine_v4_hook_create3_salt_matching, try_mine_v4_hook_create3_salt_with_contains,
+    try_mine_v4_hook_create3_salt_with_prefix, try_mine_v4_hook_salt,
+    try_mine_v4_hook_salt_matching, try_mine_v4_hook_salt_with_contains,
+    try_mine_v4_hook_salt_with_prefix, v4_hook_contains_difficulty, v4_hook_prefix_difficulty,
+};
@@ -0,0 +1,130 @@
+use alloy_primitives::{Address, B256, b256, keccak256};
+
+/// `keccak256("zksyncCreate2")`.
+pub const CREATE2_PREFIX: B256 =
+    b256!("2020dba91b30cc0006188af794c2fb30dd8520db7e2c088b7fc7c103c00ca494");
+/// `keccak256("zksyncCreate")`.
+pub const CREATE_PREFIX: B256 =
+    b256!("63bae3a9951d38e8a3fbb7b70909afc1200610fc5bc55ade242f815974674f23");
+
+/// Computes the address of a contract deployed with CREATE2 on zkSync Era.
+///
+/// ## Arguments
+/// * `sender` - The deploying contract.
+/// * `salt` - The CREATE2 salt.
+/// * `bytecode_hash` - The versioned bytecode hash produced by `zksolc`, not `keccak256(bytecode)`.
+/// * `input` - The ABI-encoded constructor arguments.
+pub fn zksync_create2_address(
+    sender: Address,
+    salt: B256,
+    bytecode_hash: B256,
+    input: &[u8],
+) -> Address {
+    zksync_create2_address_from_input_hash(sender, salt, bytecode_hash, keccak256(input))
+}
+
+/// Same as `zksync_create2_address`, taking the precomputed `keccak256(input)`.
+pub fn zksync_create2_address_from_input_hash(
+    sender: Address,
+    salt: B256,
+    bytecode_hash: B256,
+    input_hash: B256,
+) -> Address {
+    let mut bytes = [0u8; 160];
+    bytes[..32].copy_from_slice(CREATE2_PREFIX.as_slice());
+    bytes[32..64].copy_from_slice(sender.into_word().as_slice());
+    bytes[64..96].copy_from_slice(salt.as_slice());
+    bytes[96..128].copy_from_slice(bytecode_hash.as_slice());
+    bytes[128..].copy_from_slice(input_hash.as_slice());
+
+    Address::from_slice(&keccak256(bytes)[12..])
+}
+
+/// Computes the address of a contract deployed with CREATE on zkSync Era.
+///
+/// `nonce` is the deployment nonce of `sender`, which on zkSync is tracked separately from its
+/// transaction nonce.
+pub fn zksync_create_address(sender: Address, nonce: u64) -> Address {
+    let mut bytes = [0u8; 96];
+    bytes[..32].copy_from_slice(CREATE_PREFIX.as_slice());
+    bytes[32..64].copy_from_slice(sender.into_word().as_slice());
+    bytes[88..].copy_from_slice(&nonce.to_be_bytes());
+
+    Address::from_slice(&keccak256(bytes)[12..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    const SENDER: Address = address!("36615Cf349d7F6344891B1e7CA7C72883F5dc049");
+
+    #[test]
+    fn test_prefixes() {
+        assert_eq!(CREATE2_PREFIX, keccak256("zksyncCreate2"));
+        assert_eq!(CREATE_PREFIX, keccak256("zksyncCreate"));
+    }
+
+    #[test]
+    fn test_zksync_create_address() {
+        // Expected addresses were computed by calling `deployed_address_create` from the
+        // `zksync_types` crate (29.13.0), not copied from its tests.
+        let vectors = [
+            (0, address!("111c3e89ce80e62ee88318c2804920d4c96f92bb")),
+            (1, address!("4B5DF730c2e6b28E17013A1485E5d9BC41Efe021")),
+            (2, address!("26b368c3ed16313ebd6660b72d8e4439a697cb0b")),
+        ];
+        for (nonce, expected) in vectors {
+            assert_eq!(
+                zksync_create_address(SENDER, nonce),
+                expected,
+                "zkSync CREATE address mismatch for nonce {nonce}"
+            );
+        }
+        assert_ne!(
+            zksync_create_address(SENDER, 1),
+            crate::createx::create_address(SENDER, 1),
+            "zkSync CREATE must not use RLP"
+        );
+    }
+
+    #[test]
+    fn test_zksync_create2_address() {
+        // Expected addresses were computed by calling `Create2DeploymentParams::derive_address`
+        // from the `zksync_types` crate (29.13.0), which mirrors
+        // `ContractDeployer.getNewAddressCreate2`, not copied from its tests.
+        let bytecode_hash =
+            b256!("010001cb6a6e8d5f6829522f19fa9568660e0a9cd53b2e8be4deb0a679452e41");
+        let one = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+        let vectors: [(B256, &[u8], Address); 3] = [
+            (
+                B256::ZERO,
+                &[],
+                address!("3cfd6d969361fd475d81ba3e3306263ec88e1c61"),
+            ),
+            (
+                one,
+                &[],
+                address!("73a1d763e16ad377b328cc6593bd0d5e524b6a0f"),
+            ),
+            (
+                one,
+                one.as_slice(),
+                address!("327febad05525a9003ef195a6102d93ac7fd55d2"),
+            ),
+        ];
+        for (salt, input, expected) in vectors {
+            assert_eq!(
+                zksync_create2_address(SENDER, salt, bytecode_hash, input),
+                expected,
+                "zkSync CREATE2 address mismatch"
+            );
+            assert_ne!(
+                zksync_create2_address(SENDER, salt, bytecode_hash, input),
+                crate::createx::create2_address(SENDER, salt, bytecode_hash),
+                "zkSync CREATE2 must differ from the EVM formula"
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::createx::{
+    ConfigError, Create2Match, Create2Result, CreateConfig, CreateMatch, CreateResult, SaltTemplate,
+};
+use alloy_primitives::{Address, B256, Bytes};
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_serde;
+
+/// Configuration for the zkSync Era CREATE2 mining process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZkSyncCreate2Config {
+    /// The contract calling `ContractDeployer.create2`.
+    pub deployer: Address,
+    /// The versioned bytecode hash of the contract, as produced by `zksolc`.
+    pub bytecode_hash: B256,
+    /// The ABI-encoded constructor arguments.
+    #[serde(default)]
+    pub constructor_input: Bytes,
+    /// The maximum number of attempts before giving up.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_iterations: u64,
+    /// The maximum number of results to find.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_u64")
+    )]
+    pub max_results: u64,
+    /// Which salt bytes are fixed and which are mined. Defaults to mining the whole salt.
+    #[serde(default)]
+    pub salt_template: Option<SaltTemplate>,
+    /// Seed for the random number generator.
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(deserialize_with = "wasm_serde::deserialize_option_u128")
+    )]
+    pub seed: Option<u128>,
+}
+
+impl ZkSyncCreate2Config {
+    /// Returns the salt template to mine with, defaulting to mining the whole salt.
+    pub fn effective_salt_template(&self) -> SaltTemplate {
+        self.salt_template
+            .clone()
+            .unwrap_or_else(SaltTemplate::fully_mined)
+    }
+
+    /// Validates the salt template.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.effective_salt_template()
+            .validate(&[], self.max_iterations)?;
+        Ok(())
+    }
+}
+
+/// A single successful match from a zkSync CREATE2 mining operation.
+pub type ZkSyncCreate2Match = Create2Match;
+
+/// Result structure that includes zkSync CREATE2 matches and total iterations.
+pub type ZkSyncCreate2Result = Create2Result;
+
+/// Configuration for the zkSync Era CREATE mining process. `start_nonce` is the deployment
+/// nonce of the sender, as returned by `NonceHolder.getDeploymentNonce`.
+pub type ZkSyncCreateConfig = CreateConfig;
+
+/// A single successful match from a zkSync CREATE mining operation.
+pub type ZkSyncCreateMatch = CreateMatch;
+
+/// Result structure that includes zkSync CREATE matches and total iterations.
+pub type ZkSyncCreateResult = CreateResult;
@@ -0,0 +1,311 @@
+use crate::pattern::{contains_predicate, prefix_predicate, suffix_predicate};
+use crate::zksync::compute::{zksync_create_address, zksync_create2_address_from_input_hash};
+use crate::zksync::config::{
+    ZkSyncCreate2Config, ZkSyncCreate2Match, ZkSyncCreate2Result, ZkSyncCreateConfig,
+    ZkSyncCreateMatch, ZkSyncCreateResult,
+};
+use alloy_primitives::{Address, keccak256};
+#[cfg(target_arch = "wasm32")]
+use serde_wasm_bindgen::{from_value, to_value};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+const DEFAULT_SEED: u128 = 1337;
+
+/// Mines for a salt that produces a zkSync Era CREATE2 address satisfying the given predicate.
+///
+/// ## Arguments
+/// * `config` - A `ZkSyncCreate2Config` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `ZkSyncCreate2Result` containing the found salts/addresses and total iterations.
+pub fn mine_zksync_create2_salt(
+    config: &ZkSyncCreate2Config,
+    predicate: &dyn Fn(Address) -> bool,
+) -> ZkSyncCreate2Result {
+    if let Err(e) = config.validate() {
+        panic!("Invalid config: {e}");
+    }
+    let input_hash = keccak256(&config.constructor_input);
+    let mut cursor = config
+        .effective_salt_template()
+        .cursor(config.seed.unwrap_or(DEFAULT_SEED));
+    let mut results = Vec::new();
+
+    let mut total_iterations = 0;
+
+    for i in 0..config.max_iterations {
+        total_iterations = (i + 1) as usize;
+        let salt = cursor.next_salt();
+
+        let computed_address = zksync_create2_address_from_input_hash(
+            config.deployer,
+            salt,
+            config.bytecode_hash,
+            input_hash,
+        );
+
+        if predicate(computed_address) {
+            results.push(ZkSyncCreate2Match {
+                salt,
+                computed_address,
+            });
+
+            if results.len() >= config.max_results as usize {
+                break;
+            }
+        }
+    }
+
+    ZkSyncCreate2Result {
+        results,
+        total_iterations,
+    }
+}
+
+/// Mines for a salt that produces a zkSync CREATE2 address with a specific prefix.
+pub fn mine_zksync_create2_salt_with_prefix(
+    config: &ZkSyncCreate2Config,
+    prefix: &[u8],
+) -> ZkSyncCreate2Result {
+    mine_zksync_create2_salt(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a salt that produces a zkSync CREATE2 address with a specific suffix.
+pub fn mine_zksync_create2_salt_with_suffix(
+    config: &ZkSyncCreate2Config,
+    suffix: &[u8],
+) -> ZkSyncCreate2Result {
+    mine_zksync_create2_salt(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a salt that produces a zkSync CREATE2 address containing a specific byte sequence.
+pub fn mine_zksync_create2_salt_with_contains(
+    config: &ZkSyncCreate2Config,
+    contains: &[u8],
+) -> ZkSyncCreate2Result {
+    mine_zksync_create2_salt(config, &contains_predicate(contains))
+}
+
+/// Mines for a deployment nonce that produces a zkSync Era CREATE address satisfying the given
+/// predicate.
+///
+/// ## Arguments
+/// * `config` - A `ZkSyncCreateConfig` struct defining the mining parameters.
+/// * `predicate` - A closure that returns true if the computed address is the desired one.
+///
+/// ## Returns
+/// A `ZkSyncCreateResult` containing the found nonces/addresses and total iterations.
+pub fn mine_zksync_create_nonce(
+    config: &ZkSyncCreateConfig,
+    predicate: &dyn Fn(Address) -> bool,
+) -> ZkSyncCreateResult {
+    let mut results = Vec::new();
+
+    let mut total_iterations = 0;
+
+    for i in 0..config.max_iterations {
+        let Some(nonce) = config.start_nonce.checked_add(i) else {
+            break;
+        };
+        total_iterations = (i + 1) as usize;
+
+        let computed_address = zksync_create_address(config.sender, nonce);
+
+        if predicate(computed_address) {
+            results.push(ZkSyncCreateMatch {
+                nonce,
+                computed_address,
+            });
+
+            if results.len() >= config.max_results as usize {
+                break;
+            }
+        }
+    }
+
+    ZkSyncCreateResult {
+        results,
+        total_iterations,
+    }
+}
+
+/// Mines for a deployment nonce that produces a zkSync CREATE address with a specific prefix.
+pub fn mine_zksync_create_nonce_with_prefix(
+    config: &ZkSyncCreateConfig,
+    prefix: &[u8],
+) -> ZkSyncCreateResult {
+    mine_zksync_create_nonce(config, &prefix_predicate(prefix))
+}
+
+/// Mines for a deployment nonce that produces a zkSync CREATE address with a specific suffix.
+pub fn mine_zksync_create_nonce_with_suffix(
+    config: &ZkSyncCreateConfig,
+    suffix: &[u8],
+) -> ZkSyncCreateResult {
+    mine_zksync_create_nonce(config, &suffix_predicate(suffix))
+}
+
+/// Mines for a deployment nonce that produces a zkSync CREATE address containing a specific byte
+/// sequence.
+pub fn mine_zksync_create_nonce_with_contains(
+    config: &ZkSyncCreateConfig,
+    contains: &[u8],
+) -> ZkSyncCreateResult {
+    mine_zksync_create_nonce(config, &contains_predicate(contains))
+}
+
+// WASM wrapper functions
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_zksync_create2_salt_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: ZkSyncCreate2Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_zksync_create2_salt_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_zksync_create2_salt_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: ZkSyncCreate2Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_zksync_create2_salt_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_zksync_create2_salt_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: ZkSyncCreate2Config =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_zksync_create2_salt_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_zksync_create_nonce_with_prefix(
+    config: JsValue,
+    prefix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: ZkSyncCreateConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_zksync_create_nonce_with_prefix(&config, prefix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_zksync_create_nonce_with_suffix(
+    config: JsValue,
+    suffix: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: ZkSyncCreateConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_zksync_create_nonce_with_suffix(&config, suffix);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn wasm_mine_zksync_create_nonce_with_contains(
+    config: JsValue,
+    contains: &[u8],
+) -> Result<JsValue, JsValue> {
+    let config: ZkSyncCreateConfig =
+        from_value(config).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mining_result = mine_zksync_create_nonce_with_contains(&config, contains);
+    to_value(&mining_result).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zksync::compute::zksync_create2_address;
+    use alloy_primitives::{address, b256, bytes};
+
+    const SENDER: Address = address!("36615Cf349d7F6344891B1e7CA7C72883F5dc049");
+
+    #[test]
+    fn test_mine_zksync_create2_for_prefix() {
+        let config = ZkSyncCreate2Config {
+            deployer: SENDER,
+            bytecode_hash: b256!(
+                "010001cb6a6e8d5f6829522f19fa9568660e0a9cd53b2e8be4deb0a679452e41"
+            ),
+            constructor_input: bytes!(
+                "000000000000000000000000000000000000000000000000000000000000002a"
+            ),
+            max_iterations: 1_000_000,
+            max_results: 1,
+            salt_template: None,
+            seed: Some(1234),
+        };
+
+        let prefix = &[0x2e, 0x2a];
+        let mining_result = mine_zksync_create2_salt_with_prefix(&config, prefix);
+        assert!(
+            !mining_result.results.is_empty(),
+            "Failed to find address starting with 0x2e2a within {} iterations",
+            config.max_iterations
+        );
+
+        let result = &mining_result.results[0];
+        assert!(result.computed_address.starts_with(prefix));
+        assert_eq!(
+            result.computed_address,
+            zksync_create2_address(
+                config.deployer,
+                result.salt,
+                config.bytecode_hash,
+                &config.constructor_input
+            ),
+            "Address computation mismatch"
+        );
+    }
+
+    #[test]
+    fn test_mine_zksync_create_nonce() {
+        let config = ZkSyncCreateConfig {
+            sender: SENDER,
+            start_nonce: 0,
+            max_iterations: 10,
+            max_results: 1,
+        };
+
+        let expected = zksync_create_address(SENDER, 1);
+        let mining_result = mine_zksync_create_nonce(&config, &|addr| addr == expected);
+        assert_eq!(mining_result.results.len(), 1);
+        assert_eq!(
+            mining_result.results[0].nonce, 1,
+            "Should find the deployment nonce of the address"
+        );
+        assert_eq!(mining_result.total_iterations, 2);
+    }
+}
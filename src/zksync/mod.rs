@@ -0,0 +1,18 @@
+pub mod compute;
+pub mod config;
+pub mod miner;
+
+pub use compute::{
+    CREATE_PREFIX, CREATE2_PREFIX, zksync_create_address, zksync_create2_address,
+    zksync_create2_address_from_input_hash,
+};
+pub use config::{
+    ZkSyncCreate2Config, ZkSyncCreate2Match, ZkSyncCreate2Result, ZkSyncCreateConfig,
+    ZkSyncCreateMatch, ZkSyncCreateResult,
+};
+pub use miner::{
+    mine_zksync_create_nonce, mine_zksync_create_nonce_with_contains,
+    mine_zksync_create_nonce_with_prefix, mine_zksync_create_nonce_with_suffix,
+    mine_zksync_create2_salt, mine_zksync_create2_salt_with_contains,
+    mine_zksync_create2_salt_with_prefix, mine_zksync_create2_salt_with_suffix,
+};
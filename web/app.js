@@ -220,8 +220,9 @@ async function mineCreate3() {
 
     const config = {
       deployer,
-      caller,
-      chain_id: chainId,
+      sender_protection: caller ? { caller } : "zero_address",
+      cross_chain_protection:
+        chainId !== null ? { enabled: chainId } : "disabled",
       max_iterations: maxIterations,
       max_results: maxResults,
       seed: seed,